@@ -0,0 +1,675 @@
+use std::collections::HashMap;
+
+use anvil::{Angle, Axis, Length, Part, Plane, Point, Sketch, point};
+
+use crate::{
+    Area, BoundingBox, DefaultUnits, List, Member, PartNamespace, Record, Signature, Span, Vec3,
+    Volume,
+    errors::{Diagnostic, Error, sort_diagnostics, suggest},
+    lint::{apply_lint_levels, lint_statements},
+    syntax::{
+        ExprKind, Expression, Source, Statement, StmtKind, is_operator_method, parse_source_tree,
+    },
+};
+
+/// Check an oden part that is loaded into a &str for errors without executing it.
+///
+/// Returns all errors that can be found before execution, like calls with arguments of the wrong
+/// type, instead of stopping at the first one, together with the warnings of the lints. No
+/// geometry is produced.
+///
+/// # Example
+/// ```rust
+/// use oden::{Diagnostic, Error, Span, check_input};
+///
+/// let input = "
+///     part Box:
+///         part.add(Cube(10deg))
+///         part.add(Sphere(2mm, 3mm))
+/// ";
+/// assert_eq!(
+///     check_input(input),
+///     vec![
+///         Diagnostic::Error(Error::Arguments {
+///             should: vec!["Length".into()],
+///             is: vec!["Angle".into()],
+///             span: Span::from((32, 43, input)),
+///         }),
+///         Diagnostic::Error(Error::Arguments {
+///             should: vec!["Length".into()],
+///             is: vec!["Length".into(), "Length".into()],
+///             span: Span::from((62, 78, input)),
+///         }),
+///     ]
+/// )
+/// ```
+pub fn check_input(input: &str) -> Vec<Diagnostic> {
+    check_input_with_namespace(input, &PartNamespace::new())
+}
+
+/// Check an oden part that is loaded into a &str for errors, starting from a custom namespace.
+pub fn check_input_with_namespace(input: &str, namespace: &PartNamespace) -> Vec<Diagnostic> {
+    check_source(Source::new(input), namespace)
+}
+
+/// Check an oden source file for errors, starting from a custom namespace.
+///
+/// The spans of the diagnostics point into the source, so that they are displayed with its path.
+pub fn check_source(source: Source, namespace: &PartNamespace) -> Vec<Diagnostic> {
+    let input = source.text().to_string();
+    let (tree, mut errors) = parse_source_tree(source);
+    let mut statements = vec![];
+    for node in tree.statements() {
+        match node.to_statement() {
+            Ok(statement) => statements.push(statement),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    errors.extend(check_statements(&statements, namespace));
+    let diagnostics: Vec<Diagnostic> = errors
+        .into_iter()
+        .map(Diagnostic::from)
+        .chain(
+            lint_statements(&statements, namespace)
+                .into_iter()
+                .map(Diagnostic::from),
+        )
+        .collect();
+    let mut diagnostics = apply_lint_levels(diagnostics, &input, namespace);
+    sort_diagnostics(&mut diagnostics);
+    diagnostics
+}
+
+/// Infer the types of all expressions in the statements and return the errors found on the way.
+pub fn check_statements(statements: &[Statement], namespace: &PartNamespace) -> Vec<Error> {
+    let mut checker = Checker::new(namespace);
+    for statement in statements {
+        checker.statement(statement);
+    }
+    checker.errors
+}
+
+/// Return the spans of statements that call a method on a variable but result in another type,
+/// like `part.volume()`. Their result is not assigned to the variable but discarded.
+pub(crate) fn discarded_method_calls(
+    statements: &[Statement],
+    namespace: &PartNamespace,
+) -> Vec<Span> {
+    let mut checker = Checker::new(namespace);
+    for statement in statements {
+        checker.statement(statement);
+    }
+    checker.discarded
+}
+
+/// What is known about the value of an expression before it is evaluated.
+#[derive(Clone, Debug, PartialEq)]
+enum Inferred {
+    /// A value whose type can not be inferred. It is accepted everywhere.
+    Unknown,
+    /// An instance with the given type name.
+    Instance(String),
+    /// A builtin type or function from the namespace.
+    Type(Member),
+    /// A list whose elements have the inferred type.
+    List(Box<Inferred>),
+    /// A record with the inferred types of its fields.
+    Record(Vec<(String, Inferred)>),
+}
+impl Inferred {
+    fn from_type_name(type_name: &str) -> Self {
+        match type_name {
+            "List" => Self::List(Box::new(Self::Unknown)),
+            _ => Self::Instance(type_name.into()),
+        }
+    }
+
+    fn from_member(member: &Member) -> Self {
+        if let Member::Type(_) = member {
+            return Self::Type(member.clone());
+        }
+        if let Some(List(elements)) = member.downcast_ref::<List>() {
+            return Self::List(Box::new(common_type(
+                elements.iter().map(Self::from_member).collect(),
+            )));
+        }
+        if let Some(Record(fields)) = member.downcast_ref::<Record>() {
+            return Self::Record(
+                fields
+                    .iter()
+                    .map(|(name, value)| (name.clone(), Self::from_member(value)))
+                    .collect(),
+            );
+        }
+        Self::Instance(member.type_name())
+    }
+
+    /// Return the type name of the value or None if it is unknown.
+    fn type_name(&self) -> Option<String> {
+        match self {
+            Self::Unknown => None,
+            Self::Instance(type_name) => Some(type_name.clone()),
+            Self::Type(member) => Some(member.type_name()),
+            Self::List(_) => Some("List".into()),
+            Self::Record(_) => Some("Record".into()),
+        }
+    }
+}
+
+struct Checker {
+    namespace: PartNamespace,
+    variables: HashMap<String, Inferred>,
+    errors: Vec<Error>,
+    discarded: Vec<Span>,
+}
+impl Checker {
+    fn new(namespace: &PartNamespace) -> Self {
+        Checker {
+            namespace: namespace.clone(),
+            variables: HashMap::new(),
+            errors: vec![],
+            discarded: vec![],
+        }
+    }
+
+    fn statement(&mut self, statement: &Statement) {
+        match &statement.0 {
+            StmtKind::Assignment(name, expr) => {
+                let inferred = self.expression(expr);
+                self.variables.insert(name.clone(), inferred);
+            }
+            StmtKind::Empty | StmtKind::PartDeclaration(_) => (),
+            StmtKind::Expr(expr) => {
+                let inferred = self.expression(expr);
+                let Some(name) = assigned_receiver(expr) else {
+                    return;
+                };
+                let current = self.variable(&name).and_then(|c| c.type_name());
+                match (current, inferred.type_name()) {
+                    (Some(current), Some(result)) if current != result => {
+                        self.discarded.push(statement.1.clone())
+                    }
+                    _ => {
+                        self.variables.insert(name, inferred);
+                    }
+                }
+            }
+            StmtKind::Units(unit) => {
+                if self.namespace.set_default_unit(unit).is_none() {
+                    self.errors.push(Error::UnknownUnit(
+                        unit.clone(),
+                        suggest(unit, self.namespace.units().symbols()),
+                        statement.1.clone(),
+                    ));
+                }
+            }
+        }
+    }
+
+    fn expression(&mut self, expr: &Expression) -> Inferred {
+        let span = expr.span().clone();
+        match expr.kind() {
+            ExprKind::Comprehension {
+                element,
+                variable,
+                iterable,
+            } => {
+                let item = match self.expression(iterable) {
+                    Inferred::List(item) => *item,
+                    Inferred::Unknown => Inferred::Unknown,
+                    other => {
+                        self.errors.push(Error::InvalidArgument(
+                            format!(
+                                "can only iterate over a List, not a {}",
+                                other.type_name().unwrap_or_default()
+                            ),
+                            span,
+                        ));
+                        Inferred::Unknown
+                    }
+                };
+                let shadowed = self.variables.insert(variable.clone(), item);
+                let element = self.expression(element);
+                match shadowed {
+                    Some(inferred) => self.variables.insert(variable.clone(), inferred),
+                    None => self.variables.remove(variable),
+                };
+                Inferred::List(Box::new(element))
+            }
+            ExprKind::FormatString(parts) => {
+                for part in parts {
+                    self.expression(part);
+                }
+                Inferred::Instance("String".into())
+            }
+            ExprKind::Function { name, args } => {
+                let args = self.arguments(args);
+                match self.variable(name) {
+                    Some(Inferred::Type(Member::Type(function))) => {
+                        self.call(&function.signatures(), &args, span)
+                    }
+                    Some(Inferred::Unknown) => Inferred::Unknown,
+                    _ => {
+                        let functions = self.names().into_iter().filter(|name| {
+                            matches!(self.variable(name), Some(Inferred::Type(Member::Type(_))))
+                        });
+                        let suggestion = suggest(name, functions);
+                        self.errors
+                            .push(Error::UnknownFunction(name.clone(), suggestion, span));
+                        Inferred::Unknown
+                    }
+                }
+            }
+            ExprKind::Ident(name) => match self.variable(name) {
+                Some(inferred) => inferred,
+                None => {
+                    let suggestion = suggest(name, self.names());
+                    self.errors
+                        .push(Error::UnknownVariable(name.clone(), suggestion, span));
+                    Inferred::Unknown
+                }
+            },
+            ExprKind::List(elements) => Inferred::List(Box::new(common_type(
+                elements.iter().map(|e| self.expression(e)).collect(),
+            ))),
+            ExprKind::Literal(literal) => {
+                match Member::from_str_with_units(literal, self.namespace.units(), span) {
+                    Ok(member) => Inferred::from_member(&member),
+                    Err(error) => {
+                        self.errors.push(error);
+                        Inferred::Unknown
+                    }
+                }
+            }
+            ExprKind::Method {
+                receiver,
+                method,
+                args,
+            } => {
+                let receiver = self.expression(receiver);
+                let args = self.arguments(args);
+                self.method(receiver, method, &args, span)
+            }
+            ExprKind::Record(fields) => Inferred::Record(
+                fields
+                    .iter()
+                    .map(|(name, expr)| (name.clone(), self.expression(expr)))
+                    .collect(),
+            ),
+            ExprKind::Str(_) => Inferred::Instance("String".into()),
+        }
+    }
+
+    fn arguments(&mut self, args: &[Expression]) -> Vec<Inferred> {
+        args.iter().map(|arg| self.expression(arg)).collect()
+    }
+
+    /// Return the names of all variables and builtins known at this point.
+    fn names(&self) -> Vec<String> {
+        let mut names = self.namespace.names();
+        names.extend(self.variables.keys().cloned());
+        names
+    }
+
+    fn variable(&self, name: &String) -> Option<Inferred> {
+        match self.variables.get(name) {
+            Some(inferred) => Some(inferred.clone()),
+            None => self.namespace.get(name).map(Inferred::from_member),
+        }
+    }
+
+    fn method(
+        &mut self,
+        receiver: Inferred,
+        method: &str,
+        args: &[Inferred],
+        span: Span,
+    ) -> Inferred {
+        let member = match &receiver {
+            Inferred::Unknown => return Inferred::Unknown,
+            Inferred::Type(member) => member.clone(),
+            Inferred::Record(fields) => {
+                if !args.is_empty() {
+                    self.errors
+                        .push(Error::UnknownMethod(method.into(), None, span));
+                    return Inferred::Unknown;
+                }
+                return match fields.iter().find(|(name, _)| name == method) {
+                    Some((_, inferred)) => inferred.clone(),
+                    None => {
+                        self.errors.push(Error::UnknownField(method.into(), span));
+                        Inferred::Unknown
+                    }
+                };
+            }
+            Inferred::List(item) if method == "get" => {
+                self.call(&[Signature::new(&["Number"], "List")], args, span);
+                return *item.clone();
+            }
+            Inferred::List(item) if method == "sum" => {
+                self.call(&[Signature::new(&[], "List")], args, span);
+                return *item.clone();
+            }
+            Inferred::List(_) => List(vec![]).into(),
+            Inferred::Instance(type_name) => match prototype(type_name) {
+                Some(member) => member,
+                None => return Inferred::Unknown,
+            },
+        };
+        match member.callable_method(method.into(), &span) {
+            Ok(callable) if is_operator_method(method) => {
+                self.operator(&receiver, method, &callable.signatures(), args, span)
+            }
+            Ok(callable) => self.call(&callable.signatures(), args, span),
+            Err(error) => {
+                self.errors.push(error);
+                Inferred::Unknown
+            }
+        }
+    }
+
+    /// Return the type an operator method results in and record an error if no signature accepts
+    /// the operand, which is reported like the evaluation does.
+    fn operator(
+        &mut self,
+        receiver: &Inferred,
+        method: &str,
+        signatures: &[Signature],
+        args: &[Inferred],
+        span: Span,
+    ) -> Inferred {
+        let (Some(left), [arg]) = (receiver.type_name(), args) else {
+            return self.call(signatures, args, span);
+        };
+        let Some(right) = arg.type_name() else {
+            return self.call(signatures, args, span);
+        };
+        let defaults = self.namespace.default_units();
+        if signatures.is_empty()
+            || signatures
+                .iter()
+                .any(|signature| accepts(signature, &[Some(right.clone())], defaults))
+        {
+            return self.call(signatures, args, span);
+        }
+        self.errors.push(Error::IncompatibleOperands {
+            operation: method.into(),
+            left,
+            right,
+            span,
+        });
+        Inferred::Unknown
+    }
+
+    /// Return the type a call results in and record an error if no signature accepts the
+    /// arguments.
+    fn call(&mut self, signatures: &[Signature], args: &[Inferred], span: Span) -> Inferred {
+        let Some(first) = signatures.first() else {
+            return Inferred::Unknown;
+        };
+        let types: Vec<Option<String>> = args.iter().map(Inferred::type_name).collect();
+        let defaults = self.namespace.default_units();
+        let matching: Vec<&Signature> = signatures
+            .iter()
+            .filter(|signature| accepts(signature, &types, defaults))
+            .collect();
+
+        match matching.as_slice() {
+            [] => {
+                if types.iter().all(Option::is_some) {
+                    let closest = signatures
+                        .iter()
+                        .find(|signature| signature.args.len() == args.len())
+                        .unwrap_or(first);
+                    self.errors.push(Error::Arguments {
+                        should: closest.args.clone(),
+                        is: types.into_iter().flatten().collect(),
+                        span,
+                    });
+                }
+                Inferred::Unknown
+            }
+            [signature, others @ ..] if others.iter().all(|o| o.returns == signature.returns) => {
+                Inferred::from_type_name(&signature.returns)
+            }
+            _ => Inferred::Unknown,
+        }
+    }
+}
+
+/// Check if a signature accepts arguments of the given types, where unknown types are accepted
+/// everywhere and numbers are accepted where a default unit is set.
+fn accepts(signature: &Signature, types: &[Option<String>], defaults: &DefaultUnits) -> bool {
+    signature.args.len() == types.len()
+        && signature
+            .args
+            .iter()
+            .zip(types)
+            .all(|(should, is)| match is {
+                None => true,
+                Some(is) if is == should => true,
+                Some(is) => is == "Number" && defaults.for_type(should).is_some(),
+            })
+}
+
+/// Return the type shared by all elements of a list, or Unknown if they differ.
+fn common_type(types: Vec<Inferred>) -> Inferred {
+    let mut types = types.into_iter();
+    let Some(first) = types.next() else {
+        return Inferred::Unknown;
+    };
+    match types.all(|t| t == first) {
+        true => first,
+        false => Inferred::Unknown,
+    }
+}
+
+/// Return an instance of a type whose methods can be looked up without evaluating anything.
+fn prototype(type_name: &str) -> Option<Member> {
+    match type_name {
+        "Angle" => Some(Angle::from_rad(0.).into()),
+        "Area" => Some(Area::from_m2(0.).into()),
+        "Axis" => Some(Axis::<3>::x().into()),
+        "BoundingBox" => Some(
+            BoundingBox {
+                min: origin(),
+                max: origin(),
+            }
+            .into(),
+        ),
+        "Length" => Some(Length::from_m(0.).into()),
+        "Number" => Some(0.0.into()),
+        "Part" => Some(Part::empty().into()),
+        "Plane" => Some(Plane::xy().into()),
+        "Point" => Some(origin().into()),
+        "Sketch" => Some(Sketch::empty().into()),
+        "String" => Some(String::new().into()),
+        "Vec3" => Some(Vec3::from_m([0., 0., 0.]).into()),
+        "Volume" => Some(Volume::from_m3(0.).into()),
+        _ => None,
+    }
+}
+
+fn origin() -> Point<3> {
+    let zero = Length::from_m(0.);
+    point!(zero, zero, zero)
+}
+
+/// Return the variable a method call statement like `part.add(...)` is assigned to.
+pub(crate) fn assigned_receiver(expr: &Expression) -> Option<String> {
+    match expr.kind() {
+        ExprKind::Ident(name) => Some(name.clone()),
+        ExprKind::Method { receiver, .. } => assigned_receiver(receiver),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn errors(input: &str) -> Vec<Error> {
+        check_input(input)
+            .into_iter()
+            .filter_map(|diagnostic| match diagnostic {
+                Diagnostic::Error(error) => Some(error),
+                Diagnostic::Warning(_) => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_part() {
+        let input = "
+            part Box:
+                plate = {width: 4mm, depth: 5mm}
+                sketch = Rectangle(plate.width, plate.depth) - Circle(1mm)
+                part.add(sketch.extrude(Plane.XY().offset(1mm), 2mm * 3))
+                part.add([Cube(s) for s in [1mm, 2mm]])
+                print(f\"volume={part.volume()}\")
+            ";
+        assert_eq!(check_input(input), vec![])
+    }
+
+    #[test]
+    fn inferred_variable_types() {
+        let input = "
+            part Box:
+                size = 2mm * 3mm
+                part.add(Cube(size))
+            ";
+        assert_eq!(
+            errors(input),
+            vec![Error::Arguments {
+                should: vec!["Length".into()],
+                is: vec!["Area".into()],
+                span: Span::from((81, 91, input)),
+            }]
+        )
+    }
+
+    #[test]
+    fn method_on_inferred_type() {
+        let input = "
+            part Box:
+                part.add(Cube(1mm).extrude(Plane.XY(), 1mm))
+            ";
+        assert_eq!(
+            errors(input),
+            vec![Error::UnknownMethod(
+                "extrude".into(),
+                None,
+                Span::from((48, 82, input))
+            )]
+        )
+    }
+
+    #[test]
+    fn incompatible_operands() {
+        let input = "
+            part Box:
+                x = 1mm + 2deg
+            ";
+        let expected = Error::IncompatibleOperands {
+            operation: "add".into(),
+            left: "Length".into(),
+            right: "Angle".into(),
+            span: Span::from((43, 53, input)),
+        };
+        assert_eq!(errors(input), vec![expected.clone()]);
+        assert_eq!(crate::compile_input(input), Err(expected))
+    }
+
+    #[test]
+    fn incompatible_shape_operands() {
+        let input = "
+            part Box:
+                part.add(Cube(1mm) & Sphere(1mm) + 1mm)
+            ";
+        assert_eq!(
+            errors(input),
+            vec![Error::IncompatibleOperands {
+                operation: "add".into(),
+                left: "Part".into(),
+                right: "Length".into(),
+                span: Span::from((60, 77, input)),
+            }]
+        )
+    }
+
+    #[test]
+    fn methods_of_geometric_types() {
+        let input = "
+            part Box:
+                part.add(Cube(1mm))
+                print(part.bounding_box().size(), part.bounding_box().w())
+                print(Vec3(1mm, 2mm, 3mm).length(), Vec3(1mm, 2mm, 3mm).norm())
+                print(Point(1mm, 2mm, 3mm).x(), Point(1mm, 2mm, 3mm).v())
+            ";
+        let unknown_methods: Vec<String> = errors(input)
+            .into_iter()
+            .filter_map(|error| match error {
+                Error::UnknownMethod(method, _, _) => Some(method),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(unknown_methods, vec!["w", "norm", "v"])
+    }
+
+    #[test]
+    fn unclosed_parenthesis() {
+        let input = "x = (1";
+        assert_eq!(
+            errors(input),
+            vec![Error::ExpectedExpression(Span::from((4, 6, input)))]
+        )
+    }
+
+    #[test]
+    fn default_units() {
+        let input = "
+            units mm
+            part Box:
+                part.add(Cube(4))
+            ";
+        assert_eq!(check_input(input), vec![])
+    }
+
+    #[test]
+    fn unknown_variable_and_field() {
+        let input = "
+            part Box:
+                plate = {width: 4mm}
+                part.add(Cube(plate.height))
+                part.add(Cube(size))
+            ";
+        assert_eq!(
+            errors(input),
+            vec![
+                Error::UnknownField("height".into(), Span::from((90, 102, input))),
+                Error::UnknownVariable("size".into(), None, Span::from((135, 139, input))),
+            ]
+        )
+    }
+
+    #[test]
+    fn parse_errors_do_not_stop_checking() {
+        let input = "
+            part Box:
+                = 5mm
+                part.add(Sphere(1deg))
+            ";
+        assert_eq!(
+            errors(input),
+            vec![
+                Error::ExpectedIdentifyer(Span::from((39, 44, input))),
+                Error::Arguments {
+                    should: vec!["Length".into()],
+                    is: vec!["Angle".into()],
+                    span: Span::from((70, 82, input)),
+                },
+            ]
+        )
+    }
+}
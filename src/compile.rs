@@ -1,9 +1,12 @@
+use std::collections::HashSet;
+
 use anvil::Part;
 
 use crate::{
     PartNamespace,
-    errors::Error,
-    syntax::{Statement, separate_tokens_by_statement, tokenize},
+    errors::{Diagnostic, Error, Severity, sort_diagnostics},
+    lint::{apply_lint_levels, lint_statements},
+    syntax::{Source, Statement, Token, TokenKind, parse_source_tree},
 };
 
 /// Compile an oden part that is loaded into a &str.
@@ -11,18 +14,186 @@ use crate::{
 /// The file argument is only used to construct the Span, so this does not necessarily have to be a
 /// valid path.
 pub fn compile_input(input: &str) -> Result<Part, Error> {
-    let mut namespace = PartNamespace::new();
+    compile_input_with_namespace(input, &mut PartNamespace::new())
+}
 
-    let tokens = tokenize(input)?;
+/// Compile an oden part that is loaded into a &str, starting from a custom namespace.
+///
+/// This allows embedders to predefine variables, register their own units or set the default units
+/// of bare numbers. Warnings and the output of `print` are collected in the namespace.
+///
+/// # Example
+/// ```rust
+/// use anvil::{Cube, IntoLength};
+/// use oden::{PartNamespace, Unit, compile_input_with_namespace};
+///
+/// let mut namespace = PartNamespace::new();
+/// namespace.units_mut().register("hand", Unit::Length(0.1016));
+///
+/// let input = "
+///     part Box:
+///         part.add(Cube(10hand))
+/// ";
+/// assert_eq!(
+///     compile_input_with_namespace(input, &mut namespace),
+///     Ok(Cube::from_size((10. * 0.1016).m()))
+/// )
+/// ```
+pub fn compile_input_with_namespace(
+    input: &str,
+    namespace: &mut PartNamespace,
+) -> Result<Part, Error> {
+    let (part, diagnostics) = compile_input_with_diagnostics(input, namespace);
+    for diagnostic in diagnostics {
+        if let Diagnostic::Error(error) = diagnostic {
+            return Err(error);
+        }
+    }
+    Ok(part)
+}
 
-    let statements = separate_tokens_by_statement(tokens)
+/// Compile an oden part that is loaded into a &str and collect all errors and warnings.
+///
+/// Compilation continues after errors: unexpected symbols are skipped, statements that can not be
+/// parsed or executed are left out and statements that depend on a variable whose assignment
+/// failed are skipped without an error of their own. The returned part is built from the
+/// statements that succeeded and the diagnostics, including the warnings of the lints, are sorted
+/// by their position.
+///
+/// # Example
+/// ```rust
+/// use oden::{Diagnostic, Error, PartNamespace, Span, compile_input_with_diagnostics};
+///
+/// let input = "
+///     part Box:
+///         size = 4ly
+///         part.add(Cube(size))
+///         part.add(Sphere(1mm, 2mm))
+/// ";
+/// let (_, diagnostics) = compile_input_with_diagnostics(input, &mut PartNamespace::new());
+/// assert_eq!(
+///     diagnostics,
+///     vec![
+///         Diagnostic::Error(Error::UnknownUnit("ly".into(), None, Span::from((30, 33, input)))),
+///         Diagnostic::Error(Error::Arguments {
+///             should: vec!["Length".into()],
+///             is: vec!["Length".into(), "Length".into()],
+///             span: Span::from((80, 96, input)),
+///         }),
+///     ]
+/// )
+/// ```
+pub fn compile_input_with_diagnostics(
+    input: &str,
+    namespace: &mut PartNamespace,
+) -> (Part, Vec<Diagnostic>) {
+    compile_source_with_diagnostics(Source::new(input), namespace)
+}
+
+/// Compile an oden source file and collect all errors and warnings, like
+/// `compile_input_with_diagnostics`.
+///
+/// The spans of the diagnostics point into the source, so that they are displayed with its path.
+pub fn compile_source_with_diagnostics(
+    source: Source,
+    namespace: &mut PartNamespace,
+) -> (Part, Vec<Diagnostic>) {
+    let input = source.text().to_string();
+    let builtins = namespace.clone();
+    let (tree, tokenizer_errors) = parse_source_tree(source);
+    let mut errors = vec![];
+    let mut failed = HashSet::new();
+    let mut parsed = vec![];
+
+    let statements: Vec<Vec<Token>> = tree
+        .statements()
         .iter()
-        .map(Statement::from_tokens)
-        .collect::<Result<Vec<Statement>, Error>>()?;
+        .map(|node| node.statement_tokens())
+        .collect();
+    for (i, tokens) in statements.iter().enumerate() {
+        let statement = match Statement::from_tokens(tokens) {
+            Ok(statement) => statement,
+            Err(error) => {
+                if !follows_tokenizer_error(tokens, statements.get(i + 1), &tokenizer_errors) {
+                    errors.push(error);
+                }
+                if let Some(name) = assigned_name(tokens) {
+                    failed.insert(name);
+                }
+                continue;
+            }
+        };
+        parsed.push(statement.clone());
 
-    for statement in statements {
-        statement.execute(&mut namespace)?;
+        let depends_on_failure = statement
+            .references()
+            .iter()
+            .any(|name| failed.contains(name));
+        let binding = statement.binding().map(String::from);
+        let succeeded = !depends_on_failure
+            && match statement.execute(namespace) {
+                Ok(()) => true,
+                Err(error) => {
+                    errors.push(error);
+                    false
+                }
+            };
+        if let Some(name) = binding {
+            match succeeded {
+                true => failed.remove(&name),
+                false => failed.insert(name),
+            };
+        }
     }
 
-    Ok(namespace.part())
+    let diagnostics: Vec<Diagnostic> = tokenizer_errors
+        .into_iter()
+        .chain(errors)
+        .map(Diagnostic::from)
+        .chain(namespace.warnings().into_iter().map(Diagnostic::from))
+        .chain(
+            lint_statements(&parsed, &builtins)
+                .into_iter()
+                .map(Diagnostic::from),
+        )
+        .collect();
+    let mut diagnostics = apply_lint_levels(diagnostics, &input, namespace);
+    sort_diagnostics(&mut diagnostics);
+    (namespace.part(), diagnostics)
+}
+
+/// Return true if the diagnostics contain an error.
+pub fn has_errors(diagnostics: &[Diagnostic]) -> bool {
+    diagnostics
+        .iter()
+        .any(|diagnostic| diagnostic.severity() == Severity::Error)
+}
+
+/// Check if a statement was cut short by the tokenizer skipping the rest of its line, in which
+/// case parsing it fails as a consequence of the tokenizer error.
+fn follows_tokenizer_error(
+    tokens: &[Token],
+    next: Option<&Vec<Token>>,
+    tokenizer_errors: &[Error],
+) -> bool {
+    let Some(start) = tokens.first().map(|token| token.span().start()) else {
+        return false;
+    };
+    let end = next
+        .and_then(|next| next.first())
+        .map(|token| token.span().start())
+        .unwrap_or(usize::MAX);
+    tokenizer_errors.iter().any(|error| {
+        error
+            .span()
+            .is_some_and(|span| start <= span.start() && span.start() < end)
+    })
+}
+
+/// Return the name of the variable that statement tokens like `name = ...` assign to.
+fn assigned_name(tokens: &[Token]) -> Option<String> {
+    match (tokens.first()?.kind(), tokens.get(1)?.kind()) {
+        (TokenKind::Ident(name), TokenKind::Equal) => Some(name.clone()),
+        _ => None,
+    }
 }
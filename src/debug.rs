@@ -0,0 +1,355 @@
+//! Readable dumps of the intermediate results of compiling, as printed by `oden debug`.
+//!
+//! The output is meant for people investigating how a file is tokenized, parsed and evaluated.
+//! Unlike the JSON of the diagnostics, its format is not stable.
+
+use crate::{
+    PartNamespace,
+    json::{span_to_json, string},
+    syntax::{ExprKind, Expression, FormatPart, Span, Statement, StmtKind, Token},
+};
+
+/// Return the tokens with the lines and columns of their spans, one token per line.
+///
+/// The tokens of the expressions in format strings are indented below the format string.
+///
+/// # Example
+/// ```rust
+/// use oden::{debug_tokens, tokenize};
+///
+/// let tokens = tokenize("size = 5mm\n  .x").unwrap();
+/// assert_eq!(
+///     debug_tokens(&tokens),
+///     "1:1-1:5 Ident(size)
+/// 1:6-1:7 Equal
+/// 1:8-1:11 Literal(5mm)
+/// 1:11-2:1 LineBreak
+/// 2:3-2:4 Dot
+/// 2:4-2:5 Ident(x)
+/// "
+/// )
+/// ```
+pub fn debug_tokens(tokens: &[Token]) -> String {
+    let mut output = String::new();
+    write_tokens(tokens, 0, &mut output);
+    output
+}
+
+fn write_tokens(tokens: &[Token], depth: usize, output: &mut String) {
+    for token in tokens {
+        output.push_str(&format!(
+            "{}{} {}\n",
+            "  ".repeat(depth),
+            location(token.span()),
+            token.kind().to_string().replace('\n', "\\n")
+        ));
+        if let crate::TokenKind::FormatString(parts) = token.kind() {
+            for part in parts {
+                if let FormatPart::Expr(tokens) = part {
+                    write_tokens(tokens, depth + 1, output)
+                }
+            }
+        }
+    }
+}
+
+/// Return the statements and their expressions as an indented tree with one node per line.
+///
+/// # Example
+/// ```rust
+/// use oden::{Statement, debug_statements, tokenize};
+///
+/// let statement = Statement::from_tokens(&tokenize("box = Cube(size * 2)").unwrap()).unwrap();
+/// assert_eq!(
+///     debug_statements(&[statement]),
+///     "Assignment box 1:1-1:21
+///   Function Cube 1:7-1:21
+///     Method multiply 1:12-1:20
+///       Ident size 1:12-1:16
+///       Literal 2 1:19-1:20
+/// "
+/// )
+/// ```
+pub fn debug_statements(statements: &[Statement]) -> String {
+    let mut output = String::new();
+    for statement in statements {
+        statement_node(statement).write(0, &mut output)
+    }
+    output
+}
+
+/// Return the statements and their expressions as a JSON array of nodes.
+///
+/// Every node is an object with the fields `kind`, `value` (the name of a variable, function or
+/// method, the text of a literal or `null`), `span` as in the JSON of the diagnostics and
+/// `children`.
+///
+/// # Example
+/// ```rust
+/// use oden::{Statement, debug_statements_json, tokenize};
+///
+/// let statement = Statement::from_tokens(&tokenize("units mm").unwrap()).unwrap();
+/// assert_eq!(
+///     debug_statements_json(&[statement]),
+///     concat!(
+///         r#"[{"kind":"Units","value":"mm","span":{"#,
+///         r#""start":{"byte":0,"char":0,"line":1,"column":1},"#,
+///         r#""end":{"byte":8,"char":8,"line":1,"column":9}},"children":[]}]"#
+///     )
+/// )
+/// ```
+pub fn debug_statements_json(statements: &[Statement]) -> String {
+    let nodes: Vec<String> = statements
+        .iter()
+        .map(|statement| statement_node(statement).to_json())
+        .collect();
+    format!("[{}]", nodes.join(","))
+}
+
+/// Return the variables of a namespace with their type and value, one variable per line.
+///
+/// Builtins like types, functions and constants are left out unless another value was assigned
+/// to their name. The `part` is always included.
+///
+/// # Example
+/// ```rust
+/// use oden::{PartNamespace, compile_input_with_namespace, debug_namespace};
+///
+/// let input = "
+///     part Box:
+///         size = 2mm
+///         shape = Cube
+///         part.add(shape(size))
+/// ";
+/// let mut namespace = PartNamespace::new();
+/// compile_input_with_namespace(input, &mut namespace).unwrap();
+/// assert_eq!(
+///     debug_namespace(&namespace),
+///     "part: Part = <Part>
+/// shape: Type = <Cube>
+/// size: Length = 2mm
+/// "
+/// )
+/// ```
+pub fn debug_namespace(namespace: &PartNamespace) -> String {
+    let builtins = PartNamespace::new();
+    let mut names = namespace.names();
+    names.sort();
+
+    let mut output = String::new();
+    for name in names {
+        let Some(member) = namespace.get(&name) else {
+            continue;
+        };
+        let unchanged = builtins.get(&name).is_some_and(|builtin| {
+            builtin.type_name() == member.type_name() && builtin.display() == member.display()
+        });
+        if unchanged && name != "part" {
+            continue;
+        }
+        output.push_str(&format!(
+            "{}: {} = {}\n",
+            name,
+            member.type_name(),
+            member.display()
+        ));
+    }
+    output
+}
+
+/// A node of the statement and expression tree in a form that can be printed.
+struct DebugNode {
+    kind: &'static str,
+    value: Option<String>,
+    span: Span,
+    children: Vec<DebugNode>,
+}
+
+impl DebugNode {
+    fn new(kind: &'static str, value: Option<String>, span: &Span) -> Self {
+        DebugNode {
+            kind,
+            value,
+            span: span.clone(),
+            children: vec![],
+        }
+    }
+
+    fn with_children(mut self, children: Vec<DebugNode>) -> Self {
+        self.children = children;
+        self
+    }
+
+    fn write(&self, depth: usize, output: &mut String) {
+        let value = match &self.value {
+            Some(value) => format!(" {}", value.replace('\n', "\\n")),
+            None => String::new(),
+        };
+        output.push_str(&format!(
+            "{}{}{} {}\n",
+            "  ".repeat(depth),
+            self.kind,
+            value,
+            location(&self.span)
+        ));
+        for child in &self.children {
+            child.write(depth + 1, output)
+        }
+    }
+
+    fn to_json(&self) -> String {
+        let value = match &self.value {
+            Some(value) => string(value),
+            None => "null".into(),
+        };
+        let children: Vec<String> = self.children.iter().map(|child| child.to_json()).collect();
+        format!(
+            r#"{{"kind":{},"value":{},"span":{},"children":[{}]}}"#,
+            string(self.kind),
+            value,
+            span_to_json(&self.span),
+            children.join(",")
+        )
+    }
+}
+
+fn statement_node(statement: &Statement) -> DebugNode {
+    let span = &statement.1;
+    match &statement.0 {
+        StmtKind::Assignment(name, expr) => DebugNode::new("Assignment", Some(name.clone()), span)
+            .with_children(vec![expression_node(expr)]),
+        StmtKind::Empty => DebugNode::new("Empty", None, span),
+        StmtKind::Expr(expr) => {
+            DebugNode::new("Expr", None, span).with_children(vec![expression_node(expr)])
+        }
+        StmtKind::PartDeclaration(name) => {
+            DebugNode::new("PartDeclaration", Some(name.clone()), span)
+        }
+        StmtKind::Units(unit) => DebugNode::new("Units", Some(unit.clone()), span),
+    }
+}
+
+fn expression_node(expr: &Expression) -> DebugNode {
+    let span = expr.span();
+    match expr.kind() {
+        ExprKind::Comprehension {
+            element,
+            variable,
+            iterable,
+        } => DebugNode::new("Comprehension", Some(variable.clone()), span)
+            .with_children(vec![expression_node(element), expression_node(iterable)]),
+        ExprKind::FormatString(parts) => DebugNode::new("FormatString", None, span)
+            .with_children(parts.iter().map(expression_node).collect()),
+        ExprKind::Function { name, args } => DebugNode::new("Function", Some(name.clone()), span)
+            .with_children(args.iter().map(expression_node).collect()),
+        ExprKind::Ident(name) => DebugNode::new("Ident", Some(name.clone()), span),
+        ExprKind::List(elements) => DebugNode::new("List", None, span)
+            .with_children(elements.iter().map(expression_node).collect()),
+        ExprKind::Literal(value) => DebugNode::new("Literal", Some(value.clone()), span),
+        ExprKind::Method {
+            receiver,
+            method,
+            args,
+        } => DebugNode::new("Method", Some(method.clone()), span).with_children(
+            std::iter::once(receiver.as_ref())
+                .chain(args)
+                .map(expression_node)
+                .collect(),
+        ),
+        ExprKind::Record(fields) => DebugNode::new("Record", None, span).with_children(
+            fields
+                .iter()
+                .map(|(name, value)| {
+                    DebugNode::new("Field", Some(name.clone()), value.span())
+                        .with_children(vec![expression_node(value)])
+                })
+                .collect(),
+        ),
+        ExprKind::Str(text) => DebugNode::new("Str", Some(format!("{:?}", text)), span),
+    }
+}
+
+/// Return the lines and columns of a span like `2:5-2:9`.
+fn location(span: &Span) -> String {
+    let (start_line, start_column) = span.start_line_col();
+    let (end_line, end_column) = span.end_line_col();
+    format!(
+        "{}:{}-{}:{}",
+        start_line, start_column, end_line, end_column
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{parse_tree, tokenize};
+
+    fn statements(input: &str) -> Vec<Statement> {
+        parse_tree(input)
+            .0
+            .statements()
+            .iter()
+            .map(|node| node.to_statement().unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn tokens_of_format_string() {
+        let tokens = tokenize("f\"w={w}\" // a\n/* b\nc */").unwrap();
+        assert_eq!(
+            debug_tokens(&tokens),
+            "1:1-1:9 FormatString
+  1:6-1:7 Ident(w)
+1:10-1:14 Comment( a)
+1:14-2:1 LineBreak
+2:1-3:5 Comment( b\\nc )
+"
+        )
+    }
+
+    #[test]
+    fn statements_with_leading_dot() {
+        let input = "part Box:\n    part.add(Cube(1mm))\n        .move_to(1mm, 0mm, 0mm)";
+        assert_eq!(
+            debug_statements(&statements(input)),
+            "PartDeclaration Box 1:1-1:10
+Expr 2:5-3:32
+  Method move_to 2:5-3:32
+    Method add 2:5-2:24
+      Ident part 2:5-2:9
+      Function Cube 2:14-2:23
+        Literal 1mm 2:19-2:22
+    Literal 1mm 3:18-3:21
+    Literal 0mm 3:23-3:26
+    Literal 0mm 3:28-3:31
+"
+        )
+    }
+
+    #[test]
+    fn nested_expressions() {
+        let input = "x = [{d: s} for s in [\"a\", f\"{b}\"]]";
+        assert_eq!(
+            debug_statements(&statements(input)),
+            "Assignment x 1:1-1:36
+  Comprehension s 1:5-1:36
+    Record 1:6-1:12
+      Field d 1:10-1:11
+        Ident s 1:10-1:11
+    List 1:22-1:35
+      Str \"a\" 1:23-1:26
+      FormatString 1:28-1:34
+        Ident b 1:31-1:32
+"
+        )
+    }
+
+    #[test]
+    fn nested_json() {
+        let input = "print(\"a\\n\")";
+        let json = debug_statements_json(&statements(input));
+        assert!(json.starts_with(r#"[{"kind":"Expr","value":null,"#));
+        assert!(json.contains(r#"{"kind":"Function","value":"print","#));
+        assert!(json.contains(r#"{"kind":"Str","value":"\"a\\n\"","#));
+    }
+}
@@ -66,6 +66,32 @@ pub enum Error {
     /// ```
     EmptyPart(Span),
 
+    /// Occurs when a warning is found whose lint was denied, like with `--deny warnings`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Diagnostic, Error, PartNamespace, Span, Warning, check_input_with_namespace};
+    ///
+    /// let input = "
+    ///     part Box:
+    ///         size = 5mm
+    /// ";
+    /// let mut namespace = PartNamespace::new();
+    /// namespace.deny("unused");
+    ///
+    /// assert_eq!(
+    ///     check_input_with_namespace(input, &namespace),
+    ///     vec![
+    ///         Diagnostic::Warning(Warning::UnusedPart("Box".into(), Span::from((5, 14, input)))),
+    ///         Diagnostic::Error(Error::DeniedWarning(Warning::UnusedVariable(
+    ///             "size".into(),
+    ///             Span::from((23, 33, input))
+    ///         ))),
+    ///     ]
+    /// )
+    /// ```
+    DeniedWarning(Warning),
+
     /// Occurs when part of a statement is missing.
     ///
     /// # Example
@@ -105,16 +131,113 @@ pub enum Error {
     /// Occurs when a file could not be opened (probably because the path is incorrect).
     FileNotFound(PathBuf),
 
-    /// Occurs when a value or type is called that can not be constructed using a call.
+    /// Occurs when an operation is applied to values whose types or units do not fit together.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Error, eval_str, Span};
+    ///
+    /// let input = "1m + 90deg";
+    /// assert_eq!(
+    ///     eval_str(input),
+    ///     Err(Error::IncompatibleOperands {
+    ///         operation: "add".into(),
+    ///         left: "Length".into(),
+    ///         right: "Angle".into(),
+    ///         span: Span::from((0, 10, input))
+    ///     })
+    /// )
+    /// ```
+    IncompatibleOperands {
+        operation: String,
+        left: String,
+        right: String,
+        span: Span,
+    },
+
+    /// Occurs when the arguments to a callable have the correct types but invalid values.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Error, eval_str, Span};
+    ///
+    /// let input = "RegularPolygon(2, 1m)";
+    /// assert_eq!(
+    ///     eval_str(input),
+    ///     Err(Error::InvalidArgument(
+    ///         "a regular polygon needs at least 3 sides".into(),
+    ///         Span::from((0, 21, input))
+    ///     ))
+    /// )
+    /// ```
+    InvalidArgument(String, Span),
+
+    /// Occurs when a backslash in a string is followed by a character that has no escape sequence.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Error, eval_str, Span};
+    ///
+    /// let input = r#""C:\data""#;
+    /// assert_eq!(
+    ///     eval_str(input),
+    ///     Err(Error::InvalidEscape('d', Span::from((3, 5, input))))
+    /// )
+    /// ```
+    InvalidEscape(char, Span),
+
+    /// Occurs when a literal starts with a digit but is not a valid number with an optional unit.
     ///
     /// # Example
     /// ```rust
     /// use oden::{Error, eval_str, Span};
     ///
-    /// let input = "Axis()";
+    /// let input = "1.2.3";
     /// assert_eq!(
     ///     eval_str(input),
-    ///     Err(Error::NotCallable("Axis".into(), Span::from((0, 6, input))))
+    ///     Err(Error::InvalidLiteral("1.2.3".into(), Span::from((0, 5, input))))
+    /// )
+    /// ```
+    InvalidLiteral(String, Span),
+
+    /// Occurs when a variable can not be renamed, for example because the new name is already
+    /// used. The span points to a conflicting use of the new name, if there is one.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Error, Span, rename_input};
+    ///
+    /// let input = "width = 1mm\ndepth = 2mm\nprint(width * depth)";
+    /// assert_eq!(
+    ///     rename_input(input, "width", "depth"),
+    ///     Err(Error::InvalidRename(
+    ///         "renaming width to depth would clash with the variable depth".into(),
+    ///         Some(Span::from((12, 17, input)))
+    ///     ))
+    /// )
+    /// ```
+    InvalidRename(String, Option<Span>),
+
+    /// Occurs when a value or type is called that can not be constructed using a call.
+    ///
+    /// All builtin types can be called, so this only happens for types that do not implement
+    /// `Callable::call`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Callable, Error, Span};
+    ///
+    /// #[derive(Clone)]
+    /// struct Marker;
+    /// impl Callable for Marker {
+    ///     fn full_name(&self) -> String {
+    ///         "Marker".into()
+    ///     }
+    /// }
+    ///
+    /// assert_eq!(
+    ///     Marker.call(&[], Span::from((0, 8))),
+    ///     Err(Error::NotCallable("Marker".into(), Span::from((0, 8))))
     /// )
     /// ```
     NotCallable(String, Span),
@@ -130,7 +253,7 @@ pub enum Error {
     ///
     /// let input = "
     ///     part Box:
-    ///         size = &10mm  // ampersand is not an expected symbol
+    ///         size = $10mm  // dollar sign is not an expected symbol
     ///         part.add(Cube(size))
     /// ";
     /// assert_eq!(
@@ -140,19 +263,37 @@ pub enum Error {
     /// ```
     UnexpectedSymbol(Span),
 
+    /// Occurs when a field of a record is accessed that has not been defined.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Error, eval_str, Span};
+    ///
+    /// let input = "{d: 3mm}.length";
+    /// assert_eq!(
+    ///     eval_str(input),
+    ///     Err(Error::UnknownField("length".into(), Span::from((0, 15, input))))
+    /// )
+    /// ```
+    UnknownField(String, Span),
+
     /// Occurs when a function is called that has not been defined.
     ///
     /// # Example
     /// ```rust
     /// use oden::{Error, eval_str, Span};
     ///
-    /// let input = "do_magic()";
+    /// let input = "Cuboidd(1m, 2m, 3m)";
     /// assert_eq!(
     ///     eval_str(input),
-    ///     Err(Error::UnknownFunction("do_magic".into(), Span::from((0, 10, input))))
+    ///     Err(Error::UnknownFunction(
+    ///         "Cuboidd".into(),
+    ///         Some("Cuboid".into()),
+    ///         Span::from((0, 19, input))
+    ///     ))
     /// )
     /// ```
-    UnknownFunction(String, Span),
+    UnknownFunction(String, Option<String>, Span),
 
     /// Occurs when a method is called that has not been defined.
     ///
@@ -160,13 +301,17 @@ pub enum Error {
     /// ```rust
     /// use oden::{Error, eval_str, Span};
     ///
-    /// let input = "Axis.undefined()";
+    /// let input = "Cube(1m).move_too(1m, 1m, 1m)";
     /// assert_eq!(
     ///     eval_str(input),
-    ///     Err(Error::UnknownMethod("undefined".into(), Span::from((0, 16, input))))
+    ///     Err(Error::UnknownMethod(
+    ///         "move_too".into(),
+    ///         Some("move_to".into()),
+    ///         Span::from((0, 29, input))
+    ///     ))
     /// )
     /// ```
-    UnknownMethod(String, Span),
+    UnknownMethod(String, Option<String>, Span),
 
     /// Occurs when a unit is used that is unknown.
     ///
@@ -174,13 +319,13 @@ pub enum Error {
     /// ```rust
     /// use oden::{Error, eval_str, Span};
     ///
-    /// let input = "5ly"; // light years are not (yet) supported
+    /// let input = "5mn";
     /// assert_eq!(
     ///     eval_str(input),
-    ///     Err(Error::UnknownUnit("ly".into(), Span::from((0, 3, input))))
+    ///     Err(Error::UnknownUnit("mn".into(), Some("mm".into()), Span::from((0, 3, input))))
     /// )
     /// ```
-    UnknownUnit(String, Span),
+    UnknownUnit(String, Option<String>, Span),
 
     /// Occurs when a variable is referenced that has not been defined.
     ///
@@ -194,10 +339,38 @@ pub enum Error {
     /// ";
     /// assert_eq!(
     ///     compile_input(input),
-    ///     Err(Error::UnknownVariable("size".into(), Span::from((37, 41, input))))
+    ///     Err(Error::UnknownVariable("size".into(), None, Span::from((37, 41, input))))
     /// )
     /// ```
-    UnknownVariable(String, Span),
+    UnknownVariable(String, Option<String>, Span),
+
+    /// Occurs when a block comment is missing its closing `*/`.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Error, Span, tokenize};
+    ///
+    /// let input = "x /* unfinished";
+    /// assert_eq!(
+    ///     tokenize(input),
+    ///     Err(Error::UnterminatedComment(Span::from((2, 15, input))))
+    /// )
+    /// ```
+    UnterminatedComment(Span),
+
+    /// Occurs when a string is missing its closing quote before the end of the line.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Error, eval_str, Span};
+    ///
+    /// let input = r#""unfinished"#;
+    /// assert_eq!(
+    ///     eval_str(input),
+    ///     Err(Error::UnterminatedString(Span::from((0, 11, input))))
+    /// )
+    /// ```
+    UnterminatedString(Span),
 }
 impl Error {
     pub fn from_anvil<T>(result: Result<T, AnvilError>, span: Option<Span>) -> Result<T, Self> {
@@ -207,7 +380,10 @@ impl Error {
                 Err(Self::EmptyPart(span))
             }
             (Err(AnvilError::StlWrite(path)), _) => Err(Self::StlWrite(path)),
-            _ => unimplemented!(),
+            (Err(error), span) => Err(Self::InvalidArgument(
+                format!("the CAD kernel reported an error: {:?}", error),
+                span.unwrap_or_else(|| Span::from((0, 0))),
+            )),
         }
     }
     pub fn explanation(&self) -> String {
@@ -223,17 +399,37 @@ impl Error {
                     vec_to_string(is),
                 )
             }
+            Self::DeniedWarning(warning) => {
+                format!(
+                    "{} (lint {} is denied)",
+                    warning.explanation(),
+                    warning.lint()
+                )
+            }
             Self::EmptyPart(_) => "can not extrude empty Sketch".into(),
             Self::ExpectedExpression(_) => "expected an expression".into(),
             Self::ExpectedIdentifyer(_) => "expected an identifyer, like a variable name".into(),
             Self::FileNotFound(path) => format!("could not find file '{}'", path.to_string_lossy()),
+            Self::IncompatibleOperands {
+                operation,
+                left,
+                right,
+                span: _,
+            } => format!("cannot {} {} and {}", operation, left, right),
+            Self::InvalidArgument(reason, _) => reason.clone(),
+            Self::InvalidEscape(ch, _) => format!("\\{} is not a valid escape sequence", ch),
+            Self::InvalidLiteral(literal, _) => format!("{} is not a valid number", literal),
+            Self::InvalidRename(reason, _) => reason.clone(),
             Self::NotCallable(name, _) => format!("{} is not callable", name),
             Self::StlWrite(path) => format!("could not write STL to '{}'", path.to_string_lossy()),
             Self::UnexpectedSymbol(_) => "unsupported symbol in file".into(),
-            Self::UnknownFunction(name, _) => format!("function {} is not defined", name),
-            Self::UnknownMethod(name, _) => format!("method {} is not defined", name),
-            Self::UnknownVariable(name, _) => format!("variable {} is not defined", name),
-            Self::UnknownUnit(name, _) => format!("{} is not a supported unit", name),
+            Self::UnknownField(name, _) => format!("field {} is not defined", name),
+            Self::UnknownFunction(name, _, _) => format!("function {} is not defined", name),
+            Self::UnknownMethod(name, _, _) => format!("method {} is not defined", name),
+            Self::UnknownVariable(name, _, _) => format!("variable {} is not defined", name),
+            Self::UnknownUnit(name, _, _) => format!("{} is not a supported unit", name),
+            Self::UnterminatedComment(_) => "block comment is missing a closing */".into(),
+            Self::UnterminatedString(_) => "string is missing a closing quote".into(),
         }
     }
     pub fn span(&self) -> Option<&Span> {
@@ -243,37 +439,398 @@ impl Error {
                 is: _,
                 span,
             } => Some(span),
+            Self::DeniedWarning(warning) => warning.span(),
             Self::EmptyPart(span) => Some(span),
             Self::ExpectedExpression(span) => Some(span),
             Self::ExpectedIdentifyer(span) => Some(span),
             Self::FileNotFound(_) => None,
+            Self::IncompatibleOperands {
+                operation: _,
+                left: _,
+                right: _,
+                span,
+            } => Some(span),
+            Self::InvalidArgument(_, span) => Some(span),
+            Self::InvalidEscape(_, span) => Some(span),
+            Self::InvalidLiteral(_, span) => Some(span),
+            Self::InvalidRename(_, span) => span.as_ref(),
             Self::NotCallable(_, span) => Some(span),
             Self::StlWrite(_) => None,
             Self::UnexpectedSymbol(span) => Some(span),
-            Self::UnknownFunction(_, span) => Some(span),
-            Self::UnknownMethod(_, span) => Some(span),
-            Self::UnknownVariable(_, span) => Some(span),
-            Self::UnknownUnit(_, span) => Some(span),
+            Self::UnknownField(_, span) => Some(span),
+            Self::UnknownFunction(_, _, span) => Some(span),
+            Self::UnknownMethod(_, _, span) => Some(span),
+            Self::UnknownVariable(_, _, span) => Some(span),
+            Self::UnknownUnit(_, _, span) => Some(span),
+            Self::UnterminatedComment(span) => Some(span),
+            Self::UnterminatedString(span) => Some(span),
         }
     }
+    /// Return the stable code identifying the kind of this error, like `E0001`.
+    ///
+    /// Codes are never reused or changed, new variants get the next free code. A denied warning
+    /// keeps the code of the warning.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Arguments { .. } => "E0001",
+            Self::DeniedWarning(warning) => warning.code(),
+            Self::EmptyPart(_) => "E0002",
+            Self::ExpectedExpression(_) => "E0003",
+            Self::ExpectedIdentifyer(_) => "E0004",
+            Self::FileNotFound(_) => "E0005",
+            Self::IncompatibleOperands { .. } => "E0006",
+            Self::InvalidArgument(_, _) => "E0007",
+            Self::InvalidEscape(_, _) => "E0008",
+            Self::InvalidLiteral(_, _) => "E0020",
+            Self::InvalidRename(_, _) => "E0019",
+            Self::NotCallable(_, _) => "E0009",
+            Self::StlWrite(_) => "E0010",
+            Self::UnexpectedSymbol(_) => "E0011",
+            Self::UnknownField(_, _) => "E0012",
+            Self::UnknownFunction(_, _, _) => "E0013",
+            Self::UnknownMethod(_, _, _) => "E0014",
+            Self::UnknownUnit(_, _, _) => "E0015",
+            Self::UnknownVariable(_, _, _) => "E0016",
+            Self::UnterminatedComment(_) => "E0018",
+            Self::UnterminatedString(_) => "E0017",
+        }
+    }
+    /// Return the name that was probably meant instead of an unknown one.
+    pub fn suggestion(&self) -> Option<&String> {
+        match self {
+            Self::UnknownFunction(_, suggestion, _)
+            | Self::UnknownMethod(_, suggestion, _)
+            | Self::UnknownVariable(_, suggestion, _)
+            | Self::UnknownUnit(_, suggestion, _) => suggestion.as_ref(),
+            _ => None,
+        }
+    }
+    /// Return a hint on how to fix the error, like a suggestion for a misspelled name.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Error, Span};
+    ///
+    /// let error = Error::UnknownUnit("mn".into(), Some("mm".into()), Span::from((1, 3, "5mn")));
+    /// assert_eq!(error.help(), Some("did you mean `mm`?".into()))
+    /// ```
+    pub fn help(&self) -> Option<String> {
+        self.suggestion()
+            .map(|suggestion| format!("did you mean `{}`?", suggestion))
+    }
 }
 
 impl StdError for Error {}
 impl std::fmt::Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let mut text = match self.span() {
+            Some(span) => format!(
+                "error[{}]: {}{}",
+                self.code(),
+                self.explanation(),
+                span.print()
+            ),
+            None => format!("error[{}]: {}\n", self.code(), self.explanation()),
+        };
+        if let Some(help) = self.help() {
+            text.push_str(&format!("help: {}\n", help));
+        }
+        write!(f, "{}", text)
+    }
+}
+
+/// Problems that do not stop the compilation but might point to a mistake.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Warning {
+    /// Occurs when an expression statement computes a value that is not used for anything.
+    ///
+    /// Method calls on a variable that result in the type of the variable, like `part.add(...)`,
+    /// are not affected as their result is assigned to the variable, and neither are calls to
+    /// `print`. Queries like `part.volume()` are.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Diagnostic, Span, Warning, check_input};
+    ///
+    /// let input = "
+    ///     part Box:
+    ///         part.add(Cube(1mm))
+    ///         Cube(2mm)
+    /// ";
+    /// assert_eq!(
+    ///     check_input(input),
+    ///     vec![Diagnostic::Warning(Warning::DiscardedValue(Span::from((51, 60, input))))]
+    /// )
+    /// ```
+    DiscardedValue(Span),
+
+    /// Occurs when a bare number is converted into the default unit set by a `units` directive
+    /// and warnings for this are enabled.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{PartNamespace, Span, Warning, compile_input_with_namespace};
+    ///
+    /// let input = "
+    ///     units mm
+    ///     part Box:
+    ///         part.add(Cube(5))
+    /// ";
+    /// let mut namespace = PartNamespace::new();
+    /// namespace.warn_implicit_units(true);
+    ///
+    /// assert!(compile_input_with_namespace(input, &mut namespace).is_ok());
+    /// assert_eq!(
+    ///     namespace.warnings(),
+    ///     vec![Warning::ImplicitUnit("mm".into(), Span::from((50, 51, input)))]
+    /// )
+    /// ```
+    ImplicitUnit(String, Span),
+
+    /// Occurs when a variable is assigned a name that is already used by a builtin function or
+    /// type, which makes the builtin inaccessible.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Diagnostic, Span, Warning, check_input};
+    ///
+    /// let input = "
+    ///     part Box:
+    ///         Cube = 5mm
+    ///         part.add(Sphere(Cube))
+    /// ";
+    /// assert_eq!(
+    ///     check_input(input),
+    ///     vec![Diagnostic::Warning(Warning::ShadowedBuiltin(
+    ///         "Cube".into(),
+    ///         Span::from((23, 33, input))
+    ///     ))]
+    /// )
+    /// ```
+    ShadowedBuiltin(String, Span),
+
+    /// Occurs when a part is declared but nothing is ever added to it.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Diagnostic, Span, Warning, check_input};
+    ///
+    /// let input = "
+    ///     part Box:
+    ///         print(\"empty\")
+    /// ";
+    /// assert_eq!(
+    ///     check_input(input),
+    ///     vec![Diagnostic::Warning(Warning::UnusedPart("Box".into(), Span::from((5, 14, input))))]
+    /// )
+    /// ```
+    UnusedPart(String, Span),
+
+    /// Occurs when a variable is assigned but its value is never read before it is assigned again
+    /// or the file ends.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Diagnostic, Span, Warning, check_input};
+    ///
+    /// let input = "
+    ///     part Box:
+    ///         size = 5mm
+    ///         part.add(Cube(1mm))
+    /// ";
+    /// assert_eq!(
+    ///     check_input(input),
+    ///     vec![Diagnostic::Warning(Warning::UnusedVariable(
+    ///         "size".into(),
+    ///         Span::from((23, 33, input))
+    ///     ))]
+    /// )
+    /// ```
+    UnusedVariable(String, Span),
+}
+impl Warning {
+    pub fn explanation(&self) -> String {
+        match self {
+            Self::DiscardedValue(_) => "the value of this statement is never used".into(),
+            Self::ImplicitUnit(unit, _) => {
+                format!("number without unit is interpreted as {}", unit)
+            }
+            Self::ShadowedBuiltin(name, _) => {
+                format!("variable {} shadows the builtin of the same name", name)
+            }
+            Self::UnusedPart(name, _) => format!("nothing is ever added to part {}", name),
+            Self::UnusedVariable(name, _) => {
+                format!("variable {} is assigned but never used", name)
+            }
+        }
+    }
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Self::DiscardedValue(span) => Some(span),
+            Self::ImplicitUnit(_, span) => Some(span),
+            Self::ShadowedBuiltin(_, span) => Some(span),
+            Self::UnusedPart(_, span) => Some(span),
+            Self::UnusedVariable(_, span) => Some(span),
+        }
+    }
+    /// Return the stable code identifying the kind of this warning, like `W0001`.
+    ///
+    /// Codes are never reused or changed, new variants get the next free code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::DiscardedValue(_) => "W0001",
+            Self::ImplicitUnit(_, _) => "W0002",
+            Self::ShadowedBuiltin(_, _) => "W0003",
+            Self::UnusedPart(_, _) => "W0004",
+            Self::UnusedVariable(_, _) => "W0005",
+        }
+    }
+    /// Return the name of the lint this warning belongs to, as used by `--deny` and
+    /// `// oden:allow(...)` comments.
+    pub fn lint(&self) -> &'static str {
+        match self {
+            Self::DiscardedValue(_) => "discarded_value",
+            Self::ImplicitUnit(_, _) => "implicit_unit",
+            Self::ShadowedBuiltin(_, _) => "shadowed_builtin",
+            Self::UnusedPart(_, _) => "unused_part",
+            Self::UnusedVariable(_, _) => "unused",
+        }
+    }
+}
+impl std::fmt::Display for Warning {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
         let text = match self.span() {
             Some(span) => format!(
-                "error during compilation: {}{}",
+                "warning[{}]: {}{}",
+                self.code(),
                 self.explanation(),
                 span.print()
             ),
-            None => format!("error during compilation: {}\n", self.explanation()),
+            None => format!("warning[{}]: {}\n", self.code(), self.explanation()),
         };
         write!(f, "{}", text)
     }
 }
 
-fn vec_to_string(v: &Vec<String>) -> String {
+/// How serious a diagnostic is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Error,
+}
+
+/// An error or a warning found while checking or compiling a file.
+///
+/// # Example
+/// ```rust
+/// use oden::{Diagnostic, Error, Severity, Span};
+///
+/// let diagnostic = Diagnostic::from(Error::UnknownUnit("ly".into(), None, Span::from((0, 3, "5ly"))));
+/// assert_eq!(diagnostic.severity(), Severity::Error);
+/// assert_eq!(diagnostic.span(), Some(&Span::from((0, 3, "5ly"))))
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub enum Diagnostic {
+    Error(Error),
+    Warning(Warning),
+}
+impl Diagnostic {
+    pub fn severity(&self) -> Severity {
+        match self {
+            Self::Error(_) => Severity::Error,
+            Self::Warning(_) => Severity::Warning,
+        }
+    }
+    pub fn explanation(&self) -> String {
+        match self {
+            Self::Error(error) => error.explanation(),
+            Self::Warning(warning) => warning.explanation(),
+        }
+    }
+    pub fn span(&self) -> Option<&Span> {
+        match self {
+            Self::Error(error) => error.span(),
+            Self::Warning(warning) => warning.span(),
+        }
+    }
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Error(error) => error.code(),
+            Self::Warning(warning) => warning.code(),
+        }
+    }
+    pub fn suggestion(&self) -> Option<&String> {
+        match self {
+            Self::Error(error) => error.suggestion(),
+            Self::Warning(_) => None,
+        }
+    }
+}
+impl From<Error> for Diagnostic {
+    fn from(value: Error) -> Self {
+        Self::Error(value)
+    }
+}
+impl From<Warning> for Diagnostic {
+    fn from(value: Warning) -> Self {
+        Self::Warning(value)
+    }
+}
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match self {
+            Self::Error(error) => write!(f, "{}", error),
+            Self::Warning(warning) => write!(f, "{}", warning),
+        }
+    }
+}
+
+/// Sort diagnostics by their position in the file, keeping the ones without a span at the end.
+pub fn sort_diagnostics(diagnostics: &mut [Diagnostic]) {
+    diagnostics.sort_by_key(|diagnostic| match diagnostic.span() {
+        Some(span) => (0, span.start()),
+        None => (1, 0),
+    });
+}
+
+/// Return the candidate closest to a misspelled name, if it is close enough to be a likely typo.
+///
+/// Candidates are compared by their edit distance, which may be at most a third of the length of
+/// the name (and at least 1). Ties are broken by preferring the same first letter and a similar
+/// length, then alphabetically.
+pub(crate) fn suggest(name: &str, candidates: impl IntoIterator<Item = String>) -> Option<String> {
+    let length = name.chars().count();
+    let max_distance = usize::max(1, length / 3);
+    candidates
+        .into_iter()
+        .filter(|candidate| candidate != name)
+        .map(|candidate| {
+            let distance = edit_distance(name, &candidate);
+            let other_start = candidate.chars().next() != name.chars().next();
+            let length_difference = candidate.chars().count().abs_diff(length);
+            ((distance, other_start, length_difference), candidate)
+        })
+        .filter(|((distance, _, _), _)| *distance <= max_distance)
+        .min()
+        .map(|(_, candidate)| candidate)
+}
+
+/// Return the Levenshtein distance between two strings.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+fn vec_to_string(v: &[String]) -> String {
     let mut output = String::from("[");
     for (i, elem) in v.iter().enumerate() {
         output.push_str(elem);
@@ -285,3 +842,28 @@ fn vec_to_string(v: &Vec<String>) -> String {
     output.push(']');
     output
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn edit_distances() {
+        assert_eq!(edit_distance("move_too", "move_to"), 1);
+        assert_eq!(edit_distance("mn", "mm"), 1);
+        assert_eq!(edit_distance("kitten", "sitting"), 3);
+        assert_eq!(edit_distance("", "abc"), 3);
+    }
+
+    #[test]
+    fn suggestions() {
+        let candidates = || vec!["Cube".to_string(), "Cuboid".into(), "Sphere".into()];
+        assert_eq!(suggest("Cuboidd", candidates()), Some("Cuboid".into()));
+        assert_eq!(suggest("cube", candidates()), Some("Cube".into()));
+        assert_eq!(suggest("Cylinder", candidates()), None);
+        assert_eq!(
+            suggest("mn", ["in", "m", "mm"].map(String::from)),
+            Some("mm".into())
+        );
+    }
+}
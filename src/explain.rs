@@ -0,0 +1,453 @@
+/// Return a longer explanation with a worked example for an error or warning code.
+///
+/// The codes are the ones returned by `Error::code` and `Warning::code`. Lowercase codes are
+/// accepted as well.
+///
+/// # Example
+/// ```rust
+/// use oden::explain;
+///
+/// assert!(explain("E0002").unwrap().starts_with("A sketch without area was extruded."));
+/// assert_eq!(explain("E9999"), None);
+/// ```
+pub fn explain(code: &str) -> Option<&'static str> {
+    let text = match code.to_uppercase().as_str() {
+        "E0001" => {
+            r#"A function or method was called with arguments of the wrong type or number.
+
+Every builtin accepts one or more lists of argument types. The error shows the expected types next
+to the types of the given arguments.
+
+Erroneous example:
+
+    part Box:
+        part.add(Cube(10deg))
+
+`Cube` takes a single `Length` but was given an `Angle`. Pass a length instead:
+
+    part Box:
+        part.add(Cube(10mm))
+"#
+        }
+        "E0002" => {
+            r#"A sketch without area was extruded.
+
+Extruding a sketch creates a part from its area. If the sketch has no area, for example because a
+size is zero, the resulting part would be empty.
+
+Erroneous example:
+
+    part Box:
+        sketch = Circle(0m)
+        part.add(sketch.extrude(Plane.XY(), 1m))
+
+Make sure all sizes of the sketch are larger than zero:
+
+    part Box:
+        sketch = Circle(1m)
+        part.add(sketch.extrude(Plane.XY(), 1m))
+"#
+        }
+        "E0003" => {
+            r#"A statement is missing an expression.
+
+This usually happens when the right side of an assignment is left empty.
+
+Erroneous example:
+
+    part Box:
+        size =
+        part.add(Cube(size))
+
+Add the value that should be assigned:
+
+    part Box:
+        size = 5mm
+        part.add(Cube(size))
+"#
+        }
+        "E0004" => {
+            r#"A statement is missing an identifier, like a variable name.
+
+This usually happens when the left side of an assignment is left empty.
+
+Erroneous example:
+
+    part Box:
+        = 10mm
+        part.add(Cube(size))
+
+Add the name of the variable that is assigned:
+
+    part Box:
+        size = 10mm
+        part.add(Cube(size))
+"#
+        }
+        "E0005" => {
+            r#"The source file could not be opened.
+
+The path passed to oden does not point to a readable file. Check that the path is spelled
+correctly and relative to the current directory:
+
+    oden --source parts/box.oden --target box.step
+"#
+        }
+        "E0006" => {
+            r#"An operator was applied to values that do not fit together.
+
+Operators like `+` and `-` need values of the same kind, and `*` and `/` need dimensions that can
+be combined.
+
+Erroneous example:
+
+    part Box:
+        part.add(Cube(1m + 90deg))
+
+A length can not be added to an angle. Use values of the same kind:
+
+    part Box:
+        part.add(Cube(1m + 90mm))
+"#
+        }
+        "E0007" => {
+            r#"A function or method was called with arguments of the correct type but an invalid value.
+
+The error message states which value is not accepted.
+
+Erroneous example:
+
+    part Box:
+        part.add(RegularPolygon(2, 1m).extrude(Plane.XY(), 1m))
+
+A regular polygon needs at least 3 sides:
+
+    part Box:
+        part.add(RegularPolygon(6, 1m).extrude(Plane.XY(), 1m))
+"#
+        }
+        "E0008" => {
+            r#"A backslash in a string is followed by a character that has no escape sequence.
+
+The supported escape sequences are `\n`, `\t`, `\r`, `\0`, `\\`, `\"`, `\{` and `\}`.
+
+Erroneous example:
+
+    part Box:
+        print("C:\data")
+
+Escape the backslash itself to write it literally:
+
+    part Box:
+        print("C:\\data")
+"#
+        }
+        "E0009" => {
+            r#"A value was called like a function, but it can not be called.
+
+All builtin types can be called, so this only happens for types defined by programs embedding oden
+that do not implement `Callable::call`. Implement `call` for the type or do not call it.
+"#
+        }
+        "E0010" => {
+            r#"The resulting part could not be written to the target file.
+
+Check that the directory of the target path exists and is writable:
+
+    oden --source box.oden --target out/box.step
+"#
+        }
+        "E0011" => {
+            r#"A symbol was found that is not supported by the language.
+
+Erroneous example:
+
+    part Box:
+        size = $10mm
+        part.add(Cube(size))
+
+Remove the symbol:
+
+    part Box:
+        size = 10mm
+        part.add(Cube(size))
+"#
+        }
+        "E0012" => {
+            r#"A field of a record was accessed that has not been defined.
+
+Erroneous example:
+
+    part Box:
+        screw = {d: 3mm}
+        part.add(Cylinder(screw.length, screw.d))
+
+Either add the field to the record or access one that exists:
+
+    part Box:
+        screw = {d: 3mm, length: 10mm}
+        part.add(Cylinder(screw.length, screw.d))
+"#
+        }
+        "E0013" => {
+            r#"A function was called that has not been defined.
+
+If a builtin with a similar name exists, it is suggested.
+
+Erroneous example:
+
+    part Box:
+        part.add(Cuboidd(1m, 2m, 3m))
+
+Fix the spelling of the function:
+
+    part Box:
+        part.add(Cuboid(1m, 2m, 3m))
+"#
+        }
+        "E0014" => {
+            r#"A method was called that is not defined for the type of the value.
+
+If a method with a similar name exists, it is suggested.
+
+Erroneous example:
+
+    part Box:
+        part.add(Cube(1m).move_too(1m, 1m, 1m))
+
+Fix the spelling of the method:
+
+    part Box:
+        part.add(Cube(1m).move_to(1m, 1m, 1m))
+"#
+        }
+        "E0015" => {
+            r#"A number was written with a unit that is not known.
+
+If a unit with a similar symbol exists, it is suggested. Programs embedding oden can register
+additional units.
+
+Erroneous example:
+
+    part Box:
+        part.add(Cube(5mn))
+
+Use a supported unit:
+
+    part Box:
+        part.add(Cube(5mm))
+"#
+        }
+        "E0016" => {
+            r#"A variable was used that has not been defined.
+
+Variables have to be assigned before they are used. If a variable with a similar name exists, it
+is suggested.
+
+Erroneous example:
+
+    part Box:
+        part.add(Cube(size))
+
+Assign the variable first:
+
+    part Box:
+        size = 5mm
+        part.add(Cube(size))
+"#
+        }
+        "E0017" => {
+            r#"A string is missing its closing quote.
+
+Strings have to end on the line they start on.
+
+Erroneous example:
+
+    part Box:
+        print("unfinished)
+
+Add the closing quote:
+
+    part Box:
+        print("unfinished")
+"#
+        }
+        "E0018" => {
+            r#"A block comment is missing its closing `*/`.
+
+Block comments start with `/*` and end with the next `*/`. They can span several lines but are
+not nested.
+
+Erroneous example:
+
+    part Box:
+        /* a cube with a side length of 1mm
+        part.add(Cube(1mm))
+
+Close the comment:
+
+    part Box:
+        /* a cube with a side length of 1mm */
+        part.add(Cube(1mm))
+"#
+        }
+        "E0019" => {
+            r#"A variable can not be renamed.
+
+`oden rename` only renames variables that are assigned in the file, and only to a valid variable
+name that is not used yet. A rename is refused if the new name is a builtin, another variable or
+the variable of a list comprehension that contains a use of the renamed variable, because the
+renamed variable would then refer to something else.
+
+Erroneous example:
+
+    part Box:
+        width = 1mm
+        depth = 2mm
+        part.add(Cuboid(width, depth, 1mm))
+
+Running `oden rename width depth box.oden` would merge the two variables. Choose a name that is
+not used yet:
+
+    oden rename width plate_width box.oden
+"#
+        }
+        "E0020" => {
+            r#"A literal starts with a digit but is not a valid number.
+
+Numbers consist of digits with at most one decimal point and an optional exponent like `e-3`,
+directly followed by an optional unit.
+
+Erroneous example:
+
+    part Box:
+        part.add(Cube(1.2.5mm))
+
+Write the number with a single decimal point:
+
+    part Box:
+        part.add(Cube(1.25mm))
+"#
+        }
+        "W0001" => {
+            r#"The value of an expression statement is never used.
+
+Method calls on a variable that result in the type of the variable, like `part.add(...)`, assign
+their result to the variable and calls to `print` are made for their output. Other expressions on
+their own line, including queries like `part.volume()`, have no effect.
+
+Example:
+
+    part Box:
+        Cube(2mm)
+
+The cube is created and discarded. Add it to the part instead:
+
+    part Box:
+        part.add(Cube(2mm))
+
+This lint is called `discarded_value`.
+"#
+        }
+        "W0002" => {
+            r#"A number without unit was converted into the default unit.
+
+This warning is only emitted if it was enabled with `--warn-implicit-units`.
+
+Example:
+
+    units mm
+    part Box:
+        part.add(Cube(5))
+
+The 5 is interpreted as 5mm. Write the unit to make this explicit:
+
+    part Box:
+        part.add(Cube(5mm))
+
+This lint is called `implicit_unit`.
+"#
+        }
+        "W0003" => {
+            r#"A variable was assigned a name that is already used by a builtin.
+
+The builtin can not be used anymore after the assignment.
+
+Example:
+
+    part Box:
+        Cube = 5mm
+        part.add(Sphere(Cube))
+
+Choose a different name for the variable:
+
+    part Box:
+        radius = 5mm
+        part.add(Sphere(radius))
+
+This lint is called `shadowed_builtin`.
+"#
+        }
+        "W0004" => {
+            r#"A part is declared but nothing is ever added to it.
+
+The resulting file would be empty.
+
+Example:
+
+    part Box:
+        cube = Cube(1mm)
+
+Add the shapes to the part:
+
+    part Box:
+        part.add(Cube(1mm))
+
+This lint is called `unused_part`.
+"#
+        }
+        "W0005" => {
+            r#"A variable is assigned but its value is never read.
+
+This is reported if the variable is not used before it is assigned again or the file ends.
+
+Example:
+
+    part Box:
+        size = 5mm
+        size = 6mm
+        part.add(Cube(size))
+
+Remove the unused assignment:
+
+    part Box:
+        size = 6mm
+        part.add(Cube(size))
+
+This lint is called `unused`.
+"#
+        }
+        _ => return None,
+    };
+    Some(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn all_codes_are_explained() {
+        for i in 1..=20 {
+            assert!(explain(&format!("E{:04}", i)).is_some(), "E{:04}", i);
+        }
+        for i in 1..=5 {
+            assert!(explain(&format!("W{:04}", i)).is_some(), "W{:04}", i);
+        }
+    }
+
+    #[test]
+    fn lowercase_code() {
+        assert_eq!(explain("e0016"), explain("E0016"))
+    }
+}
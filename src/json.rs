@@ -0,0 +1,155 @@
+//! Machine-readable output of diagnostics, as printed by `--message-format json`.
+//!
+//! Every diagnostic is written as one JSON object on a single line. The schema is stable: fields
+//! are never removed or renamed and keep their meaning, new fields may be added.
+//!
+//! ```json
+//! {
+//!   "severity": "error",
+//!   "code": "E0015",
+//!   "message": "mn is not a supported unit",
+//!   "file": "box.oden",
+//!   "span": {
+//!     "start": {"byte": 30, "char": 30, "line": 3, "column": 16},
+//!     "end": {"byte": 33, "char": 33, "line": 3, "column": 19}
+//!   },
+//!   "suggestions": ["mm"]
+//! }
+//! ```
+//!
+//! - `severity` is `"error"` or `"warning"`.
+//! - `code` is the stable code of the error (`E....`) or warning (`W....`).
+//! - `message` is the explanation also shown in the human readable output.
+//! - `file` is the path of the source file or `null` if it is not known.
+//! - `span` is `null` for diagnostics without a location. Otherwise `start` is inclusive and
+//!   `end` exclusive. `byte` and `char` are 0-based offsets into the file, `line` and `column`
+//!   are 1-based and `column` is counted in chars.
+//! - `suggestions` contains the names that were probably meant and is empty if there are none.
+
+use std::path::Path;
+
+use crate::{Diagnostic, Severity, Span};
+
+impl Diagnostic {
+    /// Return this diagnostic as a single line JSON object.
+    ///
+    /// If no file is given, the path of the source the span points into is used.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Diagnostic, Error, Span};
+    ///
+    /// let input = "5mn";
+    /// let error = Error::UnknownUnit("mn".into(), Some("mm".into()), Span::from((0, 3, input)));
+    /// assert_eq!(
+    ///     Diagnostic::from(error).to_json(None),
+    ///     concat!(
+    ///         r#"{"severity":"error","code":"E0015","message":"mn is not a supported unit","#,
+    ///         r#""file":null,"span":{"start":{"byte":0,"char":0,"line":1,"column":1},"#,
+    ///         r#""end":{"byte":3,"char":3,"line":1,"column":4}},"suggestions":["mm"]}"#
+    ///     )
+    /// )
+    /// ```
+    pub fn to_json(&self, file: Option<&Path>) -> String {
+        let severity = match self.severity() {
+            Severity::Error => "error",
+            Severity::Warning => "warning",
+        };
+        let file = file.or_else(|| self.span().and_then(|span| span.source().path()));
+        let file = match file {
+            Some(path) => string(&path.to_string_lossy()),
+            None => "null".into(),
+        };
+        let span = match self.span() {
+            Some(span) => span_to_json(span),
+            None => "null".into(),
+        };
+        let suggestions: Vec<String> = self.suggestion().iter().map(|s| string(s)).collect();
+        format!(
+            r#"{{"severity":{},"code":{},"message":{},"file":{},"span":{},"suggestions":[{}]}}"#,
+            string(severity),
+            string(self.code()),
+            string(&self.explanation()),
+            file,
+            span,
+            suggestions.join(","),
+        )
+    }
+}
+
+pub(crate) fn span_to_json(span: &Span) -> String {
+    let (byte_start, byte_end) = span.bytes();
+    let (line_start, line_end) = span.lines();
+    let (column_start, column_end) = span.columns();
+    format!(
+        r#"{{"start":{},"end":{}}}"#,
+        position(byte_start, span.start(), line_start, column_start),
+        position(byte_end, span.end(), line_end, column_end),
+    )
+}
+
+fn position(byte: usize, char: usize, line: usize, column: usize) -> String {
+    format!(
+        r#"{{"byte":{},"char":{},"line":{},"column":{}}}"#,
+        byte, char, line, column
+    )
+}
+
+/// Return the text as a quoted JSON string.
+pub(crate) fn string(text: &str) -> String {
+    let mut output = String::from("\"");
+    for ch in text.chars() {
+        match ch {
+            '"' => output.push_str("\\\""),
+            '\\' => output.push_str("\\\\"),
+            '\n' => output.push_str("\\n"),
+            '\r' => output.push_str("\\r"),
+            '\t' => output.push_str("\\t"),
+            ch if ch.is_control() => output.push_str(&format!("\\u{:04x}", ch as u32)),
+            ch => output.push(ch),
+        }
+    }
+    output.push('"');
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Error, Warning, check_input};
+
+    #[test]
+    fn escaped_strings() {
+        assert_eq!(string("a \"b\"\n\\"), r#""a \"b\"\n\\""#);
+        assert_eq!(string("\u{1}"), r#""\u0001""#);
+    }
+
+    #[test]
+    fn without_span() {
+        let diagnostic = Diagnostic::from(Error::FileNotFound("box.oden".into()));
+        assert_eq!(
+            diagnostic.to_json(Some(Path::new("box.oden"))),
+            concat!(
+                r#"{"severity":"error","code":"E0005","message":"could not find file 'box.oden'","#,
+                r#""file":"box.oden","span":null,"suggestions":[]}"#
+            )
+        )
+    }
+
+    #[test]
+    fn warning_after_non_ascii_text() {
+        let input = "print(\"größe\")\npart Box:";
+        assert_eq!(
+            check_input(input),
+            vec![Warning::UnusedPart("Box".into(), Span::from((15, 24, input))).into()]
+        );
+        assert_eq!(
+            check_input(input)[0].to_json(None),
+            concat!(
+                r#"{"severity":"warning","code":"W0004","message":"nothing is ever added to part Box","#,
+                r#""file":null,"span":{"start":{"byte":17,"char":15,"line":2,"column":1},"#,
+                r#""end":{"byte":26,"char":24,"line":2,"column":10}},"suggestions":[]}"#
+            )
+        )
+    }
+}
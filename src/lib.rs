@@ -1,19 +1,64 @@
 use std::path::PathBuf;
 
+mod check;
 mod compile;
+mod debug;
 mod errors;
+mod explain;
+mod json;
+mod lint;
 mod namespace;
+mod rename;
 mod syntax;
 
-pub use compile::compile_input;
-pub use errors::Error;
-pub use namespace::builtins::{AxisType, PathType, PlaneType};
-pub use namespace::traits::{Callable, Instance, Type};
-pub use namespace::{Member, PartNamespace};
-pub use syntax::{ExprKind, Expression, Span, Statement, Token, TokenKind, eval_str, tokenize};
+pub use check::{check_input, check_input_with_namespace, check_source, check_statements};
+pub use compile::{
+    compile_input, compile_input_with_diagnostics, compile_input_with_namespace,
+    compile_source_with_diagnostics, has_errors,
+};
+pub use debug::{debug_namespace, debug_statements, debug_statements_json, debug_tokens};
+pub use errors::{Diagnostic, Error, Severity, Warning};
+pub use explain::explain;
+pub use lint::lint_statements;
+pub use namespace::builtins::{
+    Area, AxisType, BoundingBox, EllipseType, List, PathType, PlaneType, PointType, PolygonType,
+    PrismType, Record, RegularPolygonType, RoundedRectangleType, SlotType, Vec3, Vec3Type, Volume,
+    WedgeType,
+};
+pub use namespace::traits::{Callable, Instance, Signature, Type};
+pub use namespace::{DefaultUnits, Member, PartNamespace, Unit, UnitTable};
+pub use rename::{find_references, rename_input, rename_input_with_namespace, rename_source};
+pub use syntax::{
+    ExprKind, Expression, FormatPart, NodeKind, Source, Span, Statement, StmtKind, SyntaxElement,
+    SyntaxNode, Token, TokenKind, Trivia, TriviaKind, eval_str, parse_source_tree, parse_tree,
+    tokenize, tokenize_source_with_recovery, tokenize_with_recovery,
+};
+
+/// Check an oden file for errors without producing geometry.
+pub fn check(source: PathBuf) -> Vec<Diagnostic> {
+    check_with_namespace(source, &PartNamespace::new())
+}
+
+/// Check an oden file for errors without producing geometry, starting from a custom namespace.
+pub fn check_with_namespace(source: PathBuf, namespace: &PartNamespace) -> Vec<Diagnostic> {
+    match std::fs::read_to_string(source.clone()) {
+        Ok(input) => check_source(Source::new(input).with_path(source), namespace),
+        Err(_) => vec![Error::FileNotFound(source).into()],
+    }
+}
 
 /// Compile an oden file and write the resulting shape into an STEP file.
 pub fn compile(source: PathBuf, target: PathBuf) -> Result<(), Error> {
+    compile_with_namespace(source, target, &mut PartNamespace::new())
+}
+
+/// Compile an oden file starting from a custom namespace and write the resulting shape into an
+/// STEP file.
+pub fn compile_with_namespace(
+    source: PathBuf,
+    target: PathBuf,
+    namespace: &mut PartNamespace,
+) -> Result<(), Error> {
     use std::fs;
 
     let input = match fs::read_to_string(source.clone()) {
@@ -21,6 +66,37 @@ pub fn compile(source: PathBuf, target: PathBuf) -> Result<(), Error> {
         Err(_) => return Err(Error::FileNotFound(source.clone())),
     };
 
-    let part = compile_input(&input)?;
+    let source = Source::new(input).with_path(source);
+    let (part, diagnostics) = compile_source_with_diagnostics(source, namespace);
+    for diagnostic in diagnostics {
+        if let Diagnostic::Error(error) = diagnostic {
+            return Err(error);
+        }
+    }
     Error::from_anvil(part.write_step(target), None)
 }
+
+/// Compile an oden file starting from a custom namespace and write the resulting shape into an
+/// STEP file if no errors occurred.
+///
+/// Returns all errors and warnings instead of stopping at the first error.
+pub fn compile_with_diagnostics(
+    source: PathBuf,
+    target: PathBuf,
+    namespace: &mut PartNamespace,
+) -> Vec<Diagnostic> {
+    let input = match std::fs::read_to_string(source.clone()) {
+        Ok(text) => text,
+        Err(_) => return vec![Error::FileNotFound(source).into()],
+    };
+
+    let source = Source::new(input).with_path(source);
+    let (part, mut diagnostics) = compile_source_with_diagnostics(source, namespace);
+    if has_errors(&diagnostics) {
+        return diagnostics;
+    }
+    if let Err(error) = Error::from_anvil(part.write_step(target), None) {
+        diagnostics.push(error.into())
+    }
+    diagnostics
+}
@@ -0,0 +1,283 @@
+use regex::Regex;
+
+use crate::{
+    PartNamespace,
+    check::{assigned_receiver, discarded_method_calls},
+    errors::{Diagnostic, Error, Warning},
+    syntax::{ExprKind, Statement, StmtKind},
+};
+
+/// Find statements that are valid but probably not what was intended.
+///
+/// The lints are unused assignments, assignments shadowing a builtin, expression statements whose
+/// value is discarded and a declared part that is never added to. The builtins are the members of
+/// the namespace the statements are executed in.
+pub fn lint_statements(statements: &[Statement], namespace: &PartNamespace) -> Vec<Warning> {
+    let discarded_method_calls = discarded_method_calls(statements, namespace);
+    let mut warnings = vec![];
+
+    for (i, statement) in statements.iter().enumerate() {
+        match &statement.0 {
+            StmtKind::Assignment(name, _) => {
+                if name != "part" && namespace.get(name).is_some() {
+                    warnings.push(Warning::ShadowedBuiltin(name.clone(), statement.1.clone()));
+                }
+                if name != "part" && !is_read(name, &statements[i + 1..]) {
+                    warnings.push(Warning::UnusedVariable(name.clone(), statement.1.clone()));
+                }
+            }
+            StmtKind::Expr(expr) => {
+                let is_print = matches!(
+                    expr.kind(),
+                    ExprKind::Function { name, .. } if name == "print"
+                );
+                let updates_variable = matches!(expr.kind(), ExprKind::Method { .. })
+                    && assigned_receiver(expr).is_some()
+                    && !discarded_method_calls.contains(&statement.1);
+                if !is_print && !updates_variable {
+                    warnings.push(Warning::DiscardedValue(statement.1.clone()));
+                }
+            }
+            StmtKind::PartDeclaration(name) => {
+                let modified = statements.iter().any(|statement| {
+                    modifies_part(statement) && !discarded_method_calls.contains(&statement.1)
+                });
+                if !modified {
+                    warnings.push(Warning::UnusedPart(name.clone(), statement.1.clone()));
+                }
+            }
+            StmtKind::Empty | StmtKind::Units(_) => (),
+        }
+    }
+    warnings
+}
+
+/// Apply the lint settings to the diagnostics of a file.
+///
+/// Warnings on a line ending in a comment like `// oden:allow(unused)`, or on the line following
+/// such a comment on its own, are removed. Warnings of lints denied in the namespace are turned
+/// into errors.
+pub fn apply_lint_levels(
+    diagnostics: Vec<Diagnostic>,
+    input: &str,
+    namespace: &PartNamespace,
+) -> Vec<Diagnostic> {
+    let allowed = allow_comments(input);
+    diagnostics
+        .into_iter()
+        .filter_map(|diagnostic| match diagnostic {
+            Diagnostic::Warning(warning) => {
+                let line = warning.span().map(|span| span.lines().0);
+                let is_allowed = allowed.iter().any(|(allowed_line, lints)| {
+                    Some(*allowed_line) == line
+                        && lints
+                            .iter()
+                            .any(|lint| lint == warning.lint() || lint == "warnings")
+                });
+                match (is_allowed, namespace.is_denied(warning.lint())) {
+                    (true, _) => None,
+                    (false, true) => Some(Error::DeniedWarning(warning).into()),
+                    (false, false) => Some(warning.into()),
+                }
+            }
+            error => Some(error),
+        })
+        .collect()
+}
+
+/// Return true if the variable is read before it is assigned again.
+fn is_read(name: &String, following: &[Statement]) -> bool {
+    for statement in following {
+        if statement.references().contains(name) {
+            return true;
+        }
+        if statement.binding() == Some(name) {
+            return false;
+        }
+    }
+    false
+}
+
+/// Return true if the statement assigns to `part` or calls a method on it.
+fn modifies_part(statement: &Statement) -> bool {
+    match &statement.0 {
+        StmtKind::Assignment(name, _) => name == "part",
+        StmtKind::Expr(expr) => assigned_receiver(expr).is_some_and(|name| name == "part"),
+        _ => false,
+    }
+}
+
+/// Return the line numbers that `// oden:allow(...)` comments apply to together with the allowed
+/// lints.
+fn allow_comments(input: &str) -> Vec<(usize, Vec<String>)> {
+    let pattern = Regex::new(r"//\s*oden:allow\(([\w\s,]*)\)").expect("valid regex");
+    let mut allowed = vec![];
+    for (i, line) in input.split('\n').enumerate() {
+        let Some(captures) = pattern.captures(line) else {
+            continue;
+        };
+        let lints = captures[1]
+            .split(',')
+            .map(|lint| lint.trim().to_string())
+            .collect();
+        let own_line = line.trim_start().starts_with("//");
+        allowed.push((if own_line { i + 2 } else { i + 1 }, lints));
+    }
+    allowed
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::Cuboid;
+
+    use super::*;
+    use crate::{Span, check_input, check_input_with_namespace, compile_input};
+
+    #[test]
+    fn used_variables() {
+        let input = "
+            part Box:
+                size = 5mm
+                size = size * 2
+                part.add(Cube(size))
+            ";
+        assert_eq!(check_input(input), vec![])
+    }
+
+    #[test]
+    fn overwritten_variable() {
+        let input = "
+            part Box:
+                size = 5mm
+                size = 6mm
+                part.add(Cube(size))
+            ";
+        assert_eq!(
+            check_input(input),
+            vec![Diagnostic::Warning(Warning::UnusedVariable(
+                "size".into(),
+                Span::from((39, 49, input))
+            ))]
+        )
+    }
+
+    #[test]
+    fn discarded_values() {
+        let input = "
+            part Box:
+                part.add(Cube(1mm))
+                Cube(1mm).volume()
+                print(Cube(1mm).volume())
+                part.volume()
+                part.move_to(1mm, 0mm, 0mm)
+            ";
+        assert_eq!(
+            check_input(input),
+            vec![
+                Diagnostic::Warning(Warning::DiscardedValue(Span::from((75, 93, input)))),
+                Diagnostic::Warning(Warning::DiscardedValue(Span::from((152, 165, input)))),
+            ]
+        )
+    }
+
+    #[test]
+    fn query_does_not_modify_part() {
+        let input = "
+            part Box:
+                part.volume()
+            ";
+        assert_eq!(
+            check_input(input),
+            vec![
+                Diagnostic::Warning(Warning::UnusedPart(
+                    "Box".into(),
+                    Span::from((13, 22, input))
+                )),
+                Diagnostic::Warning(Warning::DiscardedValue(Span::from((39, 52, input)))),
+            ]
+        )
+    }
+
+    #[test]
+    fn query_does_not_rebind_variable() {
+        let input = "
+            part Box:
+                b = Cube(1mm)
+                b.volume()
+                Cube(1mm).volume()
+                part.add(b)
+            ";
+        assert_eq!(compile_input(input), Ok(Cuboid::from_mm(1., 1., 1.)))
+    }
+
+    #[test]
+    fn shadowed_custom_builtin() {
+        let input = "
+            part Box:
+                wall = 2mm
+                part.add(Cube(wall))
+            ";
+        let namespace = PartNamespace::new().insert_clone("wall".into(), 1.0.into());
+        assert_eq!(
+            check_input_with_namespace(input, &namespace),
+            vec![Diagnostic::Warning(Warning::ShadowedBuiltin(
+                "wall".into(),
+                Span::from((39, 49, input))
+            ))]
+        )
+    }
+
+    #[test]
+    fn discarded_query_on_custom_builtin() {
+        let input = "
+            part Box:
+                part.add(Cube(1mm))
+                plate.volume()
+            ";
+        let namespace =
+            PartNamespace::new().insert_clone("plate".into(), Cuboid::from_mm(1., 2., 3.).into());
+        assert_eq!(
+            check_input_with_namespace(input, &namespace),
+            vec![Diagnostic::Warning(Warning::DiscardedValue(Span::from((
+                75, 89, input
+            ))))]
+        )
+    }
+
+    #[test]
+    fn allow_comments() {
+        let input = "
+            part Box:
+                size = 5mm  // oden:allow(unused)
+                // oden:allow(unused, shadowed_builtin)
+                Sphere = 1mm
+                width = 2mm  // oden:allow(discarded_value)
+                part.add(Cube(1mm))
+            ";
+        assert_eq!(
+            check_input(input),
+            vec![Diagnostic::Warning(Warning::UnusedVariable(
+                "width".into(),
+                Span::from((174, 185, input))
+            ))]
+        )
+    }
+
+    #[test]
+    fn deny_warnings() {
+        let input = "
+            part Box:
+                Cube(1mm)  // oden:allow(warnings)
+                part.add(Cube(1mm))
+                Sphere(1mm)
+            ";
+        let mut namespace = PartNamespace::new();
+        namespace.deny("warnings");
+        assert_eq!(
+            check_input_with_namespace(input, &namespace),
+            vec![Diagnostic::Error(Error::DeniedWarning(
+                Warning::DiscardedValue(Span::from((126, 137, input)))
+            ))]
+        )
+    }
+}
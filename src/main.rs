@@ -1,29 +1,237 @@
-use clap::Parser;
-use std::path::PathBuf;
+use clap::{Parser, Subcommand, ValueEnum};
+use std::path::{Path, PathBuf};
 
-use oden::compile;
+use oden::{
+    Diagnostic, Error, PartNamespace, Source, check_with_namespace,
+    compile_source_with_diagnostics, compile_with_diagnostics, debug_namespace, debug_statements,
+    debug_statements_json, debug_tokens, explain, has_errors, parse_source_tree, rename_source,
+    tokenize_source_with_recovery,
+};
 
 #[derive(Parser, Debug)]
-#[command(version, about, long_about = None)]
+#[command(version, about, long_about = None, subcommand_negates_reqs = true)]
 struct Args {
+    #[command(subcommand)]
+    command: Option<Command>,
+
     /// Path to the oden file.
-    #[arg(short, long)]
-    source: PathBuf,
+    #[arg(short, long, required = true)]
+    source: Option<PathBuf>,
 
     /// Target path of the STL file.
-    #[arg(short, long)]
-    target: PathBuf,
+    #[arg(short, long, required = true)]
+    target: Option<PathBuf>,
 
     /// If set, no console ouput is produced
     #[arg(short, long, action)]
     quiet: bool,
+
+    /// Unit of numbers without a unit where a length or an angle is expected (e.g. mm or deg).
+    #[arg(short, long, global = true)]
+    units: Vec<String>,
+
+    /// If set, a warning is printed for every number that is implicitly converted into a unit.
+    #[arg(long, action, global = true)]
+    warn_implicit_units: bool,
+
+    /// Turn the warnings of a lint (like unused) into errors. Use `warnings` to deny all lints.
+    #[arg(long, global = true)]
+    deny: Vec<String>,
+
+    /// Format of errors and warnings. `json` prints one JSON object per line.
+    #[arg(long, value_enum, default_value_t = MessageFormat::Human, global = true)]
+    message_format: MessageFormat,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, ValueEnum)]
+enum MessageFormat {
+    Human,
+    Json,
+}
+
+#[derive(Subcommand, Debug)]
+enum Command {
+    /// Report all errors of an oden file without producing geometry.
+    Check {
+        /// Path to the oden file.
+        source: PathBuf,
+    },
+    /// Print a detailed explanation of an error or warning code, like E0002.
+    Explain {
+        /// The code shown with the error or warning.
+        code: String,
+    },
+    /// Print the tokens, statements or final variables of an oden file.
+    Debug {
+        #[command(subcommand)]
+        output: DebugOutput,
+    },
+    /// Rename a variable and all references to it in an oden file.
+    Rename {
+        /// The current name of the variable.
+        old: String,
+        /// The new name of the variable.
+        new: String,
+        /// Path to the oden file, which is changed in place.
+        source: PathBuf,
+    },
+}
+
+#[derive(Subcommand, Debug)]
+enum DebugOutput {
+    /// Print the tokens of an oden file with their lines and columns.
+    Tokens {
+        /// Path to the oden file.
+        source: PathBuf,
+    },
+    /// Print the statements and expressions of an oden file as an indented tree.
+    Ast {
+        /// Print the tree as JSON instead.
+        #[arg(long, action)]
+        json: bool,
+        /// Path to the oden file.
+        source: PathBuf,
+    },
+    /// Compile an oden file and print the variables of the final namespace with their types and
+    /// values.
+    Namespace {
+        /// Path to the oden file.
+        source: PathBuf,
+    },
 }
 
 /// Compile an oden file and write the resulting shape into an STEP file.
 fn main() {
     let args = Args::parse();
-    match (compile(args.source, args.target), args.quiet) {
-        (Err(error), false) => println!("{}", error),
-        _ => (),
+    let mut namespace = PartNamespace::new();
+    for lint in &args.deny {
+        namespace.deny(lint)
+    }
+    namespace.warn_implicit_units(args.warn_implicit_units);
+    for unit in &args.units {
+        if namespace.set_default_unit(unit).is_none() {
+            eprintln!("error: {} is not a supported unit", unit);
+            std::process::exit(1)
+        }
+    }
+
+    if let Some(Command::Explain { code }) = &args.command {
+        match explain(code) {
+            Some(text) => print!("{}", text),
+            None => {
+                println!("error: {} is not a known error or warning code", code);
+                std::process::exit(1)
+            }
+        }
+        return;
+    }
+    if let Some(Command::Debug { output }) = &args.command {
+        debug(output, &mut namespace, args.message_format);
+        return;
+    }
+    if let Some(Command::Rename { old, new, source }) = &args.command {
+        let result = match std::fs::read_to_string(source) {
+            Ok(text) => rename_source(Source::new(text).with_path(source), old, new, &namespace),
+            Err(_) => Err(Error::FileNotFound(source.clone())),
+        };
+        let text = match result {
+            Ok(text) => text,
+            Err(error) => {
+                report(&[error.into()], source, args.message_format);
+                std::process::exit(1)
+            }
+        };
+        if std::fs::write(source, text).is_err() {
+            println!("error: could not write '{}'", source.to_string_lossy());
+            std::process::exit(1)
+        }
+        return;
+    }
+    if let Some(Command::Check { source }) = args.command {
+        let diagnostics = check_with_namespace(source.clone(), &namespace);
+        report(&diagnostics, &source, args.message_format);
+        if has_errors(&diagnostics) {
+            std::process::exit(1)
+        }
+        return;
+    }
+    let (Some(source), Some(target)) = (args.source, args.target) else {
+        unreachable!("clap requires a source and a target without a subcommand")
+    };
+
+    let diagnostics = compile_with_diagnostics(source.clone(), target, &mut namespace);
+    if !args.quiet {
+        for line in namespace.output() {
+            match args.message_format {
+                MessageFormat::Human => println!("{}", line),
+                MessageFormat::Json => eprintln!("{}", line),
+            }
+        }
+        report(&diagnostics, &source, args.message_format);
+    }
+    if has_errors(&diagnostics) {
+        std::process::exit(1)
+    }
+}
+
+/// Print the diagnostics of a file in the requested format.
+fn report(diagnostics: &[Diagnostic], source: &Path, format: MessageFormat) {
+    for diagnostic in diagnostics {
+        match format {
+            MessageFormat::Human => println!("{}", diagnostic),
+            MessageFormat::Json => println!("{}", diagnostic.to_json(Some(source))),
+        }
+    }
+}
+
+/// Print the requested intermediate result of compiling a file, followed by its diagnostics.
+fn debug(output: &DebugOutput, namespace: &mut PartNamespace, format: MessageFormat) {
+    let source = match output {
+        DebugOutput::Tokens { source }
+        | DebugOutput::Ast { source, .. }
+        | DebugOutput::Namespace { source } => source,
+    };
+    let text = match std::fs::read_to_string(source) {
+        Ok(text) => Source::new(text).with_path(source),
+        Err(_) => {
+            report(
+                &[Error::FileNotFound(source.clone()).into()],
+                source,
+                format,
+            );
+            std::process::exit(1)
+        }
+    };
+
+    let diagnostics: Vec<Diagnostic> = match output {
+        DebugOutput::Tokens { .. } => {
+            let (tokens, errors) = tokenize_source_with_recovery(text);
+            print!("{}", debug_tokens(&tokens));
+            errors.into_iter().map(Diagnostic::from).collect()
+        }
+        DebugOutput::Ast { json, .. } => {
+            let (tree, mut errors) = parse_source_tree(text);
+            let mut statements = vec![];
+            for node in tree.statements() {
+                match node.to_statement() {
+                    Ok(statement) => statements.push(statement),
+                    Err(error) => errors.push(error),
+                }
+            }
+            match json {
+                true => println!("{}", debug_statements_json(&statements)),
+                false => print!("{}", debug_statements(&statements)),
+            }
+            errors.into_iter().map(Diagnostic::from).collect()
+        }
+        DebugOutput::Namespace { .. } => {
+            let (_, diagnostics) = compile_source_with_diagnostics(text, namespace);
+            print!("{}", debug_namespace(namespace));
+            diagnostics
+        }
+    };
+    report(&diagnostics, source, format);
+    if has_errors(&diagnostics) {
+        std::process::exit(1)
     }
 }
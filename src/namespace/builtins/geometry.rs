@@ -0,0 +1,262 @@
+use anvil::{Angle, Axis, Dir, Length, Plane, Point, point};
+
+use crate::{Error, Instance, Member, Span, match_args};
+
+/// Return the coordinates of a point in meters.
+pub fn point_to_m(point: Point<3>) -> [f64; 3] {
+    [point.x().m(), point.y().m(), point.z().m()]
+}
+
+/// Assert that computed values match the expected ones up to the precision of the kernel.
+#[cfg(test)]
+pub fn assert_close(actual: &[f64], expected: &[f64]) {
+    assert_eq!(actual.len(), expected.len());
+    for (a, e) in actual.iter().zip(expected) {
+        assert!((a - e).abs() < 1e-6, "{:?} != {:?}", actual, expected)
+    }
+}
+
+/// Return a point from coordinates in meters.
+pub fn point_from_m(coordinates: [f64; 3]) -> Point<3> {
+    point!(
+        Length::from_m(coordinates[0]),
+        Length::from_m(coordinates[1]),
+        Length::from_m(coordinates[2])
+    )
+}
+
+/// Return the coordinates of a 2D point in meters.
+pub fn point_2d_to_m(point: Point<2>) -> [f64; 2] {
+    [point.x().m(), point.y().m()]
+}
+
+/// Return a 2D point from coordinates in meters.
+pub fn point_2d_from_m(coordinates: [f64; 2]) -> Point<2> {
+    point!(
+        Length::from_m(coordinates[0]),
+        Length::from_m(coordinates[1])
+    )
+}
+
+/// Return the components of a direction.
+pub fn dir_to_array(dir: Dir<3>) -> [f64; 3] {
+    [dir.x(), dir.y(), dir.z()]
+}
+
+pub fn add(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] + b[0], a[1] + b[1], a[2] + b[2]]
+}
+
+pub fn subtract(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+pub fn scale(a: [f64; 3], factor: f64) -> [f64; 3] {
+    [a[0] * factor, a[1] * factor, a[2] * factor]
+}
+
+pub fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+pub fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+/// Rotate a vector around a unit-length axis using Rodrigues' rotation formula.
+pub fn rotate(vector: [f64; 3], axis: [f64; 3], angle: Angle) -> [f64; 3] {
+    let (sin, cos) = angle.rad().sin_cos();
+    add(
+        add(scale(vector, cos), scale(cross(axis, vector), sin)),
+        scale(axis, dot(axis, vector) * (1. - cos)),
+    )
+}
+
+/// Return a unit vector perpendicular to the given unit vector.
+///
+/// The global x-axis projected onto the plane defined by `normal` is preferred, so that the
+/// standard planes keep their usual orientation. If `normal` is parallel to the x-axis, the
+/// y-axis is used instead.
+pub fn perpendicular(normal: [f64; 3]) -> [f64; 3] {
+    let reference = if normal[0].abs() > 0.9 {
+        [0., 1., 0.]
+    } else {
+        [1., 0., 0.]
+    };
+    let projected = subtract(reference, scale(normal, dot(reference, normal)));
+    scale(projected, 1. / dot(projected, projected).sqrt())
+}
+
+/// Interpret the arguments as a point followed by a direction.
+///
+/// The point can either be a `Point` or three `Length`s and the direction is given as three
+/// numbers, like `Axis(Point(0m, 0m, 1m), 0, 0, 1)` or `Axis(0m, 0m, 1m, 0, 0, 1)`. The
+/// direction is normalized and an error is returned if it has zero length.
+pub fn point_and_direction_from_args(
+    args: &[Member],
+    span: &Span,
+) -> Result<([f64; 3], [f64; 3]), Error> {
+    let should: &[&str] = match args.first() {
+        Some(point) if point.downcast_ref::<Point<3>>().is_some() => {
+            &["Point", "Number", "Number", "Number"]
+        }
+        _ => &["Length", "Length", "Length", "Number", "Number", "Number"],
+    };
+    let arguments_error = || Error::Arguments {
+        should: should.iter().map(|name| name.to_string()).collect(),
+        is: args.iter().map(|arg| arg.type_name()).collect(),
+        span: span.clone(),
+    };
+
+    let (point, components) = match args {
+        [point, dx, dy, dz] => match point.downcast_ref::<Point<3>>() {
+            Some(point) => (point_to_m(*point), [dx, dy, dz]),
+            None => return Err(arguments_error()),
+        },
+        [x, y, z, dx, dy, dz] => {
+            let mut point = [0.; 3];
+            for (coordinate, arg) in point.iter_mut().zip([x, y, z]) {
+                *coordinate = arg
+                    .downcast_ref::<Length>()
+                    .ok_or_else(arguments_error)?
+                    .m();
+            }
+            (point, [dx, dy, dz])
+        }
+        _ => return Err(arguments_error()),
+    };
+    let mut direction = [0.; 3];
+    for (component, arg) in direction.iter_mut().zip(components) {
+        *component = *arg.downcast_ref::<f64>().ok_or_else(arguments_error)?;
+    }
+
+    let norm = dot(direction, direction).sqrt();
+    if norm == 0. {
+        return Err(Error::InvalidArgument(
+            "a direction can not be a zero vector".into(),
+            span.clone(),
+        ));
+    }
+    Ok((point, scale(direction, 1. / norm)))
+}
+
+/// Interpret the arguments as a 3D point given either as a `Point` or as three `Length`s.
+pub fn point_3d_from_args(args: &[Member], span: &Span) -> Result<Point<3>, Error> {
+    if let [point] = args
+        && let Some(point) = point.downcast_ref::<Point<3>>()
+    {
+        return Ok(*point);
+    }
+    let (x, y, z) = match_args!(Length, Length, Length, args, span);
+    Ok(point!(x, y, z))
+}
+
+/// Interpret the arguments as a 2D point given either as a `Point` or as two `Length`s.
+pub fn point_2d_from_args(args: &[Member], span: &Span) -> Result<Point<2>, Error> {
+    if let [point] = args
+        && let Some(point) = point.downcast_ref::<Point<2>>()
+    {
+        return Ok(*point);
+    }
+    let (x, y) = match_args!(Length, Length, args, span);
+    Ok(point!(x, y))
+}
+
+/// Return an axis from an origin in meters and a unit-length direction.
+///
+/// An error is returned if the kernel rejects the direction, like one that rounding reduced to a
+/// zero vector.
+pub fn axis_from_m(origin: [f64; 3], direction: [f64; 3], span: &Span) -> Result<Axis<3>, Error> {
+    Ok(Axis::<3>::new(
+        point_from_m(origin),
+        dir_from_array(direction, span)?,
+    ))
+}
+
+/// Return a plane from an origin in meters and unit-length x and normal directions.
+///
+/// An error is returned if the kernel rejects the directions, like an x direction that is not
+/// perpendicular to the normal.
+pub fn plane_from_m(
+    origin: [f64; 3],
+    x: [f64; 3],
+    normal: [f64; 3],
+    span: &Span,
+) -> Result<Plane, Error> {
+    Plane::new(
+        point_from_m(origin),
+        dir_from_array(x, span)?,
+        dir_from_array(normal, span)?,
+    )
+    .map_err(|_| {
+        Error::InvalidArgument(
+            "the x direction of a plane has to be perpendicular to its normal".into(),
+            span.clone(),
+        )
+    })
+}
+
+/// Return a direction from its components.
+fn dir_from_array(direction: [f64; 3], span: &Span) -> Result<Dir<3>, Error> {
+    Dir::try_from(direction).map_err(|_| {
+        Error::InvalidArgument("a direction can not be a zero vector".into(), span.clone())
+    })
+}
+
+/// Return the right operand of a boolean operation on shapes, like the `Part` in `part.add(other)`.
+pub fn shape_operand<'a, T: Instance>(
+    operation: &str,
+    left: &str,
+    args: &'a [Member],
+    span: &Span,
+) -> Result<&'a T, Error> {
+    match args {
+        [arg] => match arg.downcast_ref::<T>() {
+            Some(operand) => Ok(operand),
+            None => Err(Error::IncompatibleOperands {
+                operation: operation.into(),
+                left: left.into(),
+                right: arg.type_name(),
+                span: span.clone(),
+            }),
+        },
+        _ => Err(Error::Arguments {
+            should: vec![left.into()],
+            is: args.iter().map(|arg| arg.type_name()).collect(),
+            span: span.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn axis_with_zero_direction() {
+        let span = Span::from((0, 4));
+        assert_eq!(
+            axis_from_m([0.; 3], [0.; 3], &span),
+            Err(Error::InvalidArgument(
+                "a direction can not be a zero vector".into(),
+                span
+            ))
+        )
+    }
+
+    #[test]
+    fn plane_with_zero_normal() {
+        let span = Span::from((0, 4));
+        assert_eq!(
+            plane_from_m([0.; 3], [1., 0., 0.], [0.; 3], &span),
+            Err(Error::InvalidArgument(
+                "a direction can not be a zero vector".into(),
+                span
+            ))
+        )
+    }
+}
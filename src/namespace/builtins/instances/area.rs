@@ -0,0 +1,55 @@
+use crate::length_power;
+
+length_power!(
+    /// A square quantity, like the area of a part.
+    Area,
+    "Area",
+    2,
+    "square",
+    (from_m2, from_cm2, from_mm2),
+    (m2, cm2, mm2)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Area;
+    use crate::eval_str;
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            eval_str("Rectangle(1m, 2m).area() + Rectangle(3m, 1m).area()"),
+            Ok(Area::from_m2(5.).into())
+        )
+    }
+
+    #[test]
+    fn divide() {
+        assert_eq!(
+            eval_str("Rectangle(2m, 3m).area() / 2"),
+            Ok(Area::from_m2(3.).into())
+        )
+    }
+
+    #[test]
+    fn multiply() {
+        assert_eq!(
+            eval_str("Rectangle(2m, 3m).area() * 2"),
+            Ok(Area::from_m2(12.).into())
+        )
+    }
+
+    #[test]
+    fn subtract() {
+        assert_eq!(
+            eval_str("Rectangle(2m, 3m).area() - Rectangle(1m, 1m).area()"),
+            Ok(Area::from_m2(5.).into())
+        )
+    }
+
+    #[test]
+    fn unit_conversion() {
+        assert_eq!(Area::from_cm2(1.).mm2(), 100.);
+        assert_eq!(Area::from_mm2(1e6).m2(), 1.);
+    }
+}
@@ -1,8 +1,25 @@
-use anvil::Axis;
+use std::sync::Arc;
 
-use crate::{from_instance_member, namespace::traits::Instance};
+use anvil::{Angle, Axis};
+
+use crate::{
+    Callable, Member, Signature, from_instance_member, match_args,
+    namespace::{
+        builtins::geometry::{
+            add, axis_from_m, dir_to_array, point_3d_from_args, point_to_m, rotate, subtract,
+        },
+        traits::Instance,
+    },
+};
 
 impl Instance for Axis<3> {
+    fn methods(&self) -> Vec<Box<dyn Callable>> {
+        let arc = Arc::new(*self);
+        vec![
+            Box::new(MoveTo(Arc::clone(&arc))),
+            Box::new(Rotate(Arc::clone(&arc))),
+        ]
+    }
     fn eq(&self, other: &dyn Instance) -> bool {
         match other.downcast_ref::<Axis<3>>() {
             Some(o) => self == o,
@@ -14,3 +31,81 @@ impl Instance for Axis<3> {
     }
 }
 from_instance_member!(Axis<3>);
+
+#[derive(Clone, Debug, PartialEq)]
+struct MoveTo(Arc<Axis<3>>);
+impl Callable for MoveTo {
+    fn full_name(&self) -> String {
+        "Axis.move_to".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![
+            Signature::new(&["Length", "Length", "Length"], "Axis"),
+            Signature::new(&["Point"], "Axis"),
+        ]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        let origin = point_3d_from_args(args, &span)?;
+        Ok(axis_from_m(point_to_m(origin), dir_to_array(self.0.direction), &span)?.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Rotate(Arc<Axis<3>>);
+impl Callable for Rotate {
+    fn full_name(&self) -> String {
+        "Axis.rotate".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Axis", "Angle"], "Axis")]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        let (around, angle) = match_args!(Axis::<3>, Angle, args, span);
+        let around_origin = point_to_m(around.origin);
+        let around_direction = dir_to_array(around.direction);
+
+        let relative_origin = subtract(point_to_m(self.0.origin), around_origin);
+        Ok(axis_from_m(
+            add(
+                around_origin,
+                rotate(relative_origin, around_direction, angle),
+            ),
+            rotate(dir_to_array(self.0.direction), around_direction, angle),
+            &span,
+        )?
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn move_to() {
+        assert_eq!(
+            eval_str("Axis.Z().move_to(1m, 2m, 3m)"),
+            eval_str("Axis(1m, 2m, 3m, 0, 0, 1)")
+        )
+    }
+
+    #[test]
+    fn rotate() {
+        let axis = eval_str("Axis.Z().move_to(1m, 0m, 0m).rotate(Axis.Y(), 90deg)").unwrap();
+        let axis = axis.downcast_ref::<Axis<3>>().unwrap();
+
+        let origin = point_to_m(axis.origin);
+        let direction = dir_to_array(axis.direction);
+        assert!(
+            subtract(origin, [0., 0., -1.])
+                .iter()
+                .all(|c| c.abs() < 1e-9)
+        );
+        assert!(
+            subtract(direction, [1., 0., 0.])
+                .iter()
+                .all(|c| c.abs() < 1e-9)
+        );
+    }
+}
@@ -0,0 +1,118 @@
+use std::sync::Arc;
+
+use anvil::Point;
+
+use crate::{
+    Callable, Member, from_instance_member, match_args,
+    namespace::{
+        builtins::{
+            geometry::{point_to_m, subtract},
+            instances::vec3::Vec3,
+        },
+        traits::Instance,
+    },
+};
+
+/// The smallest axis-aligned box that contains a part, as returned by `Part.bounding_box()`.
+///
+/// Its extent is read through methods: `min()` and `max()` return the corners with the smallest
+/// and largest coordinates as a `Point` and `size()` returns the distance between them as a
+/// `Vec3`. Single dimensions are `Length`s, for example `part.bounding_box().size().z()`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct BoundingBox {
+    pub min: Point<3>,
+    pub max: Point<3>,
+}
+
+impl Instance for BoundingBox {
+    fn methods(&self) -> Vec<Box<dyn Callable>> {
+        let self_clone = *self;
+        vec![
+            Box::new(Max(Arc::new(self_clone))),
+            Box::new(Min(Arc::new(self_clone))),
+            Box::new(Size(Arc::new(self_clone))),
+        ]
+    }
+    fn eq(&self, other: &dyn Instance) -> bool {
+        match other.downcast_ref::<BoundingBox>() {
+            Some(o) => self == o,
+            None => false,
+        }
+    }
+    fn type_name(&self) -> String {
+        "BoundingBox".into()
+    }
+}
+from_instance_member!(BoundingBox);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Max(Arc<BoundingBox>);
+impl Callable for Max {
+    fn full_name(&self) -> String {
+        "BoundingBox.max".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        Ok(self.0.max.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Min(Arc<BoundingBox>);
+impl Callable for Min {
+    fn full_name(&self) -> String {
+        "BoundingBox.min".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        Ok(self.0.min.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Size(Arc<BoundingBox>);
+impl Callable for Size {
+    fn full_name(&self) -> String {
+        "BoundingBox.size".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        Ok(Vec3::from_m(subtract(point_to_m(self.0.max), point_to_m(self.0.min))).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{eval_str, namespace::builtins::geometry::assert_close};
+
+    #[test]
+    fn max() {
+        let max = eval_str("Cuboid(2m, 4m, 6m).bounding_box().max()").unwrap();
+        assert_close(
+            &point_to_m(*max.downcast_ref::<Point<3>>().unwrap()),
+            &[1., 2., 3.],
+        )
+    }
+
+    #[test]
+    fn min() {
+        let min = eval_str("Cuboid(2m, 4m, 6m).bounding_box().min()").unwrap();
+        assert_close(
+            &point_to_m(*min.downcast_ref::<Point<3>>().unwrap()),
+            &[-1., -2., -3.],
+        )
+    }
+
+    #[test]
+    fn size() {
+        let size = eval_str("Cuboid(2m, 4m, 6m).bounding_box().size()").unwrap();
+        assert_close(&size.downcast_ref::<Vec3>().unwrap().to_m(), &[2., 4., 6.])
+    }
+
+    #[test]
+    fn size_x() {
+        let x = eval_str("Cuboid(2m, 4m, 6m).bounding_box().size().x()").unwrap();
+        assert_close(&[x.downcast_ref::<anvil::Length>().unwrap().m()], &[2.])
+    }
+}
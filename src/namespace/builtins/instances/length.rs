@@ -1,18 +1,10 @@
-use std::{ops::Deref, sync::Arc};
-
 use anvil::Length;
 
-use crate::{Callable, Member, from_instance_member, match_args, namespace::traits::Instance};
+use crate::{Callable, from_instance_member, namespace::traits::Instance, quantity_methods};
 
 impl Instance for Length {
     fn methods(&self) -> Vec<Box<dyn Callable>> {
-        let self_clone = *self;
-        vec![
-            Box::new(Add(Arc::new(self_clone))),
-            Box::new(Divide(Arc::new(self_clone))),
-            Box::new(Multiply(Arc::new(self_clone))),
-            Box::new(Subtract(Arc::new(self_clone))),
-        ]
+        quantity_methods(*self)
     }
     fn eq(&self, other: &dyn Instance) -> bool {
         match other.downcast_ref::<Length>() {
@@ -23,56 +15,12 @@ impl Instance for Length {
     fn type_name(&self) -> String {
         "Length".into()
     }
-}
-from_instance_member!(Length);
-
-#[derive(Clone, Debug, PartialEq)]
-struct Add(Arc<Length>);
-impl Callable for Add {
-    fn full_name(&self) -> String {
-        "Length.add".into()
-    }
-    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(Length, args, span);
-        Ok((*self.0.deref() + *other).into())
-    }
-}
-
-#[derive(Clone, Debug, PartialEq)]
-struct Divide(Arc<Length>);
-impl Callable for Divide {
-    fn full_name(&self) -> String {
-        "Length.divide".into()
-    }
-    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(f64, args, span);
-        Ok((*self.0.deref() / *other).into())
-    }
-}
-
-#[derive(Clone, Debug, PartialEq)]
-struct Multiply(Arc<Length>);
-impl Callable for Multiply {
-    fn full_name(&self) -> String {
-        "Length.multiply".into()
-    }
-    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(f64, args, span);
-        Ok((*self.0.deref() * *other).into())
-    }
-}
-
-#[derive(Clone, Debug, PartialEq)]
-struct Subtract(Arc<Length>);
-impl Callable for Subtract {
-    fn full_name(&self) -> String {
-        "Length.subtract".into()
-    }
-    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(Length, args, span);
-        Ok((*self.0.deref() - *other).into())
+    fn display(&self) -> String {
+        format!("{}mm", self.mm())
     }
 }
+from_instance_member!(Length);
+quantity_methods!(Length, "Length");
 
 #[cfg(test)]
 mod tests {
@@ -89,11 +37,21 @@ mod tests {
         assert_eq!(eval_str("6m / 2"), Ok(3.m().into()))
     }
 
+    #[test]
+    fn modulo() {
+        assert_eq!(eval_str("10m % 4m"), Ok(2.m().into()))
+    }
+
     #[test]
     fn multiply() {
         assert_eq!(eval_str("3m * 2"), Ok(6.m().into()))
     }
 
+    #[test]
+    fn power() {
+        assert_eq!(eval_str("10m ^ 1"), Ok(10.m().into()))
+    }
+
     #[test]
     fn subtract() {
         assert_eq!(eval_str("10m - 3m"), Ok(7.m().into()))
@@ -0,0 +1,192 @@
+use std::rc::Rc;
+
+use crate::{
+    Callable, Error, Member, Signature, Span, from_instance_member, match_args,
+    namespace::traits::Instance,
+};
+
+/// An ordered collection of values, like `[1mm, 2mm, 3mm]`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct List(pub Vec<Member>);
+
+impl Instance for List {
+    fn methods(&self) -> Vec<Box<dyn Callable>> {
+        let rc = Rc::new(self.clone());
+        vec![
+            Box::new(Get(Rc::clone(&rc))),
+            Box::new(Len(Rc::clone(&rc))),
+            Box::new(Sum(Rc::clone(&rc))),
+        ]
+    }
+    fn eq(&self, other: &dyn Instance) -> bool {
+        match other.downcast_ref::<List>() {
+            Some(o) => self == o,
+            None => false,
+        }
+    }
+    fn type_name(&self) -> String {
+        "List".into()
+    }
+    fn display(&self) -> String {
+        let elements: Vec<String> = self.0.iter().map(Member::display).collect();
+        format!("[{}]", elements.join(", "))
+    }
+}
+from_instance_member!(List);
+
+/// Return the element at an index, which is what `xs[i]` evaluates to.
+///
+/// Negative indices count from the end of the list.
+#[derive(Clone, Debug, PartialEq)]
+struct Get(Rc<List>);
+impl Callable for Get {
+    fn full_name(&self) -> String {
+        "List.get".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let index = *match_args!(f64, args, span);
+        let len = self.0.0.len() as f64;
+        if index.fract() != 0. {
+            return Err(Error::InvalidArgument(
+                format!("a list index has to be a whole number, not {}", index),
+                span,
+            ));
+        }
+        let position = if index < 0. { len + index } else { index };
+        if position < 0. || position >= len {
+            return Err(Error::InvalidArgument(
+                format!(
+                    "index {} is out of bounds for a list of length {}",
+                    index, len
+                ),
+                span,
+            ));
+        }
+        Ok(self.0.0[position as usize].clone())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Len(Rc<List>);
+impl Callable for Len {
+    fn full_name(&self) -> String {
+        "List.len".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Number")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        match_args!(args, span);
+        Ok((self.0.0.len() as f64).into())
+    }
+}
+
+/// Add up all elements using their `add` method, so a list of parts results in their union.
+#[derive(Clone, Debug, PartialEq)]
+struct Sum(Rc<List>);
+impl Callable for Sum {
+    fn full_name(&self) -> String {
+        "List.sum".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        match_args!(args, span);
+        let mut elements = self.0.0.iter();
+        let Some(first) = elements.next() else {
+            return Ok(0.0.into());
+        };
+        elements.try_fold(first.clone(), |sum, element| {
+            sum.method("add".into(), std::slice::from_ref(element), &span)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::{Cube, IntoLength};
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn literal() {
+        assert_eq!(
+            eval_str("[1m, 2, Cube(1m)]"),
+            Ok(List(vec![
+                1.m().into(),
+                2.0.into(),
+                Cube::from_size(1.m()).into()
+            ])
+            .into())
+        )
+    }
+
+    #[test]
+    fn nested_literal() {
+        assert_eq!(
+            eval_str("[[1], []]"),
+            Ok(List(vec![List(vec![1.0.into()]).into(), List(vec![]).into()]).into())
+        )
+    }
+
+    #[test]
+    fn comprehension() {
+        assert_eq!(
+            eval_str("[x * 2 for x in [1m, 2m]]"),
+            Ok(List(vec![2.m().into(), 4.m().into()]).into())
+        )
+    }
+
+    #[test]
+    fn comprehension_over_non_list() {
+        let input = "[x for x in 5]";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "can only iterate over a List, not a Number".into(),
+                Span::from((0, 14, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn get() {
+        assert_eq!(eval_str("[1m, 2m, 3m][1]"), Ok(2.m().into()))
+    }
+
+    #[test]
+    fn get_from_end() {
+        assert_eq!(eval_str("[1m, 2m, 3m][-1]"), Ok(3.m().into()))
+    }
+
+    #[test]
+    fn get_out_of_bounds() {
+        let input = "[1m, 2m][2]";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "index 2 is out of bounds for a list of length 2".into(),
+                Span::from((0, 11, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn get_in_expression() {
+        assert_eq!(eval_str("[1m, 2m][0] + [1m, 2m][1] * 2"), Ok(5.m().into()))
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(eval_str("[1m, 2m, 3m].len()"), Ok(3.0.into()))
+    }
+
+    #[test]
+    fn sum() {
+        assert_eq!(eval_str("[1m, 2m, 3m].sum()"), Ok(6.m().into()))
+    }
+
+    #[test]
+    fn sum_of_empty_list() {
+        assert_eq!(eval_str("[].sum()"), Ok(0.0.into()))
+    }
+}
@@ -1,8 +1,16 @@
 mod angle;
+pub mod area;
 mod axis;
+pub mod bounding_box;
 mod length;
+pub mod list;
 mod number;
 mod part;
 mod path;
 mod plane;
+mod point;
+pub mod record;
 mod sketch;
+mod string;
+pub mod vec3;
+pub mod volume;
@@ -1,20 +1,15 @@
-use std::{ops::Deref, sync::Arc};
-
-use crate::{Callable, Member, from_instance_member, match_args, namespace::traits::Instance};
+use crate::{Callable, from_instance_member, namespace::traits::Instance, quantity_methods};
 
 impl Instance for f64 {
     fn methods(&self) -> Vec<Box<dyn Callable>> {
-        let self_clone = *self;
-        vec![
-            Box::new(Add(Arc::new(self_clone))),
-            Box::new(Divide(Arc::new(self_clone))),
-            Box::new(Multiply(Arc::new(self_clone))),
-            Box::new(Subtract(Arc::new(self_clone))),
-        ]
+        quantity_methods(*self)
     }
     fn type_name(&self) -> String {
         "Number".into()
     }
+    fn display(&self) -> String {
+        self.to_string()
+    }
     fn eq(&self, other: &dyn Instance) -> bool {
         match other.downcast_ref::<f64>() {
             Some(o) => self == o,
@@ -23,54 +18,7 @@ impl Instance for f64 {
     }
 }
 from_instance_member!(f64);
-
-#[derive(Clone, Debug, PartialEq)]
-struct Add(Arc<f64>);
-impl Callable for Add {
-    fn full_name(&self) -> String {
-        "Number.add".into()
-    }
-    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(f64, args, span);
-        Ok((*self.0.deref() + *other).into())
-    }
-}
-
-#[derive(Clone, Debug, PartialEq)]
-struct Divide(Arc<f64>);
-impl Callable for Divide {
-    fn full_name(&self) -> String {
-        "Number.divide".into()
-    }
-    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(f64, args, span);
-        Ok((*self.0.deref() / *other).into())
-    }
-}
-
-#[derive(Clone, Debug, PartialEq)]
-struct Multiply(Arc<f64>);
-impl Callable for Multiply {
-    fn full_name(&self) -> String {
-        "Number.multiply".into()
-    }
-    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(f64, args, span);
-        Ok((*self.0.deref() * *other).into())
-    }
-}
-
-#[derive(Clone, Debug, PartialEq)]
-struct Subtract(Arc<f64>);
-impl Callable for Subtract {
-    fn full_name(&self) -> String {
-        "Number.subtract".into()
-    }
-    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(f64, args, span);
-        Ok((*self.0.deref() - *other).into())
-    }
-}
+quantity_methods!(f64, "Number");
 
 #[cfg(test)]
 mod tests {
@@ -86,11 +34,21 @@ mod tests {
         assert_eq!(eval_str("6 / 2"), Ok(3.0.into()))
     }
 
+    #[test]
+    fn modulo() {
+        assert_eq!(eval_str("7 % 3"), Ok(1.0.into()))
+    }
+
     #[test]
     fn multiply() {
         assert_eq!(eval_str("3 * 2"), Ok(6.0.into()))
     }
 
+    #[test]
+    fn power() {
+        assert_eq!(eval_str("2 ^ 10"), Ok(1024.0.into()))
+    }
+
     #[test]
     fn subtract() {
         assert_eq!(eval_str("3 - 2"), Ok(1.0.into()))
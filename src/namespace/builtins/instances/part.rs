@@ -1,8 +1,17 @@
 use std::rc::Rc;
 
-use anvil::{Axis, Length, Part, point};
+use anvil::{Axis, Error as AnvilError, Part};
 
-use crate::{Callable, Member, from_instance_member, match_args, namespace::traits::Instance};
+use crate::{
+    Callable, Error, Member, Signature, from_instance_member, match_args,
+    namespace::{
+        builtins::{
+            geometry::{point_3d_from_args, shape_operand},
+            instances::{area::Area, bounding_box::BoundingBox, list::List, volume::Volume},
+        },
+        traits::Instance,
+    },
+};
 
 impl Instance for Part {
     fn type_name(&self) -> String {
@@ -12,10 +21,14 @@ impl Instance for Part {
         let rc = Rc::new(self.clone());
         vec![
             Box::new(Add(Rc::clone(&rc))),
+            Box::new(AreaMethod(Rc::clone(&rc))),
+            Box::new(BoundingBoxMethod(Rc::clone(&rc))),
+            Box::new(CenterOfMass(Rc::clone(&rc))),
             Box::new(CircularPattern(Rc::clone(&rc))),
             Box::new(Intersect(Rc::clone(&rc))),
             Box::new(MoveTo(Rc::clone(&rc))),
             Box::new(Subtract(Rc::clone(&rc))),
+            Box::new(VolumeMethod(Rc::clone(&rc))),
         ]
     }
     fn eq(&self, other: &dyn Instance) -> bool {
@@ -33,18 +46,97 @@ impl Callable for Add {
     fn full_name(&self) -> String {
         "Part.add".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![
+            Signature::new(&["Part"], "Part"),
+            Signature::new(&["List"], "Part"),
+        ]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(Part, args, span);
+        if let [Member::Instance(arg)] = args
+            && let Some(List(parts)) = arg.downcast_ref::<List>()
+        {
+            let mut union = self.0.as_ref().clone();
+            for part in parts {
+                let part = shape_operand::<Part>("add", "Part", std::slice::from_ref(part), &span)?;
+                union = union.add(part);
+            }
+            return Ok(union.into());
+        }
+        let other = shape_operand::<Part>("add", "Part", args, &span)?;
         Ok((self.0.add(other)).into())
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+struct AreaMethod(Rc<Part>);
+impl Callable for AreaMethod {
+    fn full_name(&self) -> String {
+        "Part.area".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Area")]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        Ok(Area::from_m2(self.0.area()).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct BoundingBoxMethod(Rc<Part>);
+impl Callable for BoundingBoxMethod {
+    fn full_name(&self) -> String {
+        "Part.bounding_box".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "BoundingBox")]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        let (min, max) = match self.0.bounding_box() {
+            Err(AnvilError::EmptyPart) => return Err(empty_part_query("bounding box", span)),
+            result => Error::from_anvil(result, Some(span))?,
+        };
+        Ok(BoundingBox { min, max }.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct CenterOfMass(Rc<Part>);
+impl Callable for CenterOfMass {
+    fn full_name(&self) -> String {
+        "Part.center_of_mass".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Point")]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        match self.0.center() {
+            Err(AnvilError::EmptyPart) => Err(empty_part_query("center of mass", span)),
+            result => Ok(Error::from_anvil(result, Some(span))?.into()),
+        }
+    }
+}
+
+/// Return the error for a query that has no answer on a part without any geometry.
+fn empty_part_query(query: &str, span: crate::Span) -> Error {
+    Error::InvalidArgument(
+        format!("can not query the {} of an empty Part", query),
+        span,
+    )
+}
+
 #[derive(Clone, Debug, PartialEq)]
 struct CircularPattern(Rc<Part>);
 impl Callable for CircularPattern {
     fn full_name(&self) -> String {
         "Part.circular_pattern".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Axis", "Number"], "Part")]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
         let (around, instances) = match_args!(Axis::<3>, f64, args, span);
         Ok((self.0.circular_pattern(around, instances as u8)).into())
@@ -57,8 +149,11 @@ impl Callable for Intersect {
     fn full_name(&self) -> String {
         "Part.intersect".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Part"], "Part")]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(Part, args, span);
+        let other = shape_operand::<Part>("intersect", "Part", args, &span)?;
         Ok((self.0.intersect(other)).into())
     }
 }
@@ -69,9 +164,15 @@ impl Callable for MoveTo {
     fn full_name(&self) -> String {
         "Part.move_to".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![
+            Signature::new(&["Length", "Length", "Length"], "Part"),
+            Signature::new(&["Point"], "Part"),
+        ]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let (x, y, z) = match_args!(Length, Length, Length, args, span);
-        Ok((self.0.move_to(point!(x, y, z))).into())
+        let point = point_3d_from_args(args, &span)?;
+        Ok((self.0.move_to(point)).into())
     }
 }
 
@@ -81,16 +182,39 @@ impl Callable for Subtract {
     fn full_name(&self) -> String {
         "Part.subtract".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Part"], "Part")]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(Part, args, span);
+        let other = shape_operand::<Part>("subtract", "Part", args, &span)?;
         Ok((self.0.subtract(other)).into())
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+struct VolumeMethod(Rc<Part>);
+impl Callable for VolumeMethod {
+    fn full_name(&self) -> String {
+        "Part.volume".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Volume")]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        Ok(Volume::from_m3(self.0.volume()).into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::eval_str;
-    use anvil::{Axis, Cube, Cuboid, IntoLength, point};
+    use crate::{
+        Span, eval_str,
+        namespace::builtins::geometry::{assert_close, point_to_m},
+    };
+    use anvil::{Axis, Cube, Cuboid, IntoLength, Point, point};
+
+    use super::*;
 
     #[test]
     fn add() {
@@ -102,6 +226,92 @@ mod tests {
         )
     }
 
+    #[test]
+    fn add_list() {
+        assert_eq!(
+            eval_str("Cube(1m).add([Cube(2m), Cube(3m)])"),
+            Ok(Cube::from_size(1.m())
+                .add(&Cube::from_size(2.m()))
+                .add(&Cube::from_size(3.m()))
+                .into())
+        )
+    }
+
+    #[test]
+    fn add_list_with_length() {
+        let input = "Cube(1m).add([Cube(2m), 3m])";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::IncompatibleOperands {
+                operation: "add".into(),
+                left: "Part".into(),
+                right: "Length".into(),
+                span: Span::from((0, 28, input))
+            })
+        )
+    }
+
+    #[test]
+    fn add_length() {
+        let input = "Cube(1m) + 1m";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::IncompatibleOperands {
+                operation: "add".into(),
+                left: "Part".into(),
+                right: "Length".into(),
+                span: Span::from((0, 13, input))
+            })
+        )
+    }
+
+    #[test]
+    fn area() {
+        let area = eval_str("Cuboid(1m, 2m, 3m).area()").unwrap();
+        assert_close(&[area.downcast_ref::<Area>().unwrap().m2()], &[22.])
+    }
+
+    #[test]
+    fn bounding_box() {
+        let bounding_box = eval_str("Cube(2m).move_to(1m, 1m, 1m).bounding_box()").unwrap();
+        let bounding_box = bounding_box.downcast_ref::<BoundingBox>().unwrap();
+        assert_close(&point_to_m(bounding_box.min), &[0., 0., 0.]);
+        assert_close(&point_to_m(bounding_box.max), &[2., 2., 2.])
+    }
+
+    #[test]
+    fn center_of_mass() {
+        let center = eval_str("Cube(1m).move_to(2m, 3m, 4m).center_of_mass()").unwrap();
+        assert_close(
+            &point_to_m(*center.downcast_ref::<Point<3>>().unwrap()),
+            &[2., 3., 4.],
+        )
+    }
+
+    #[test]
+    fn bounding_box_of_empty_part() {
+        let input = "part.bounding_box()";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "can not query the bounding box of an empty Part".into(),
+                Span::from((0, 19, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn center_of_mass_of_empty_part() {
+        let input = "part.center_of_mass()";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "can not query the center of mass of an empty Part".into(),
+                Span::from((0, 21, input))
+            ))
+        )
+    }
+
     #[test]
     fn circular_pattern() {
         assert_eq!(
@@ -133,6 +343,16 @@ mod tests {
         )
     }
 
+    #[test]
+    fn move_to_point() {
+        assert_eq!(
+            eval_str("Cube(1m).move_to(Point(2m, 3m, 4m))"),
+            Ok(Cube::from_size(1.m())
+                .move_to(point!(2.m(), 3.m(), 4.m()))
+                .into())
+        )
+    }
+
     #[test]
     fn subtract() {
         assert_eq!(
@@ -142,4 +362,10 @@ mod tests {
                 .into())
         )
     }
+
+    #[test]
+    fn volume() {
+        let volume = eval_str("Cuboid(1m, 2m, 3m).volume()").unwrap();
+        assert_close(&[volume.downcast_ref::<Volume>().unwrap().m3()], &[6.])
+    }
 }
@@ -1,8 +1,27 @@
-use anvil::Plane;
+use std::sync::Arc;
 
-use crate::{from_instance_member, namespace::traits::Instance};
+use anvil::{Angle, Axis, Length, Plane};
+
+use crate::{
+    Callable, Member, Signature, from_instance_member, match_args,
+    namespace::{
+        builtins::geometry::{
+            add, dir_to_array, plane_from_m, point_3d_from_args, point_to_m, rotate, scale,
+            subtract,
+        },
+        traits::Instance,
+    },
+};
 
 impl Instance for Plane {
+    fn methods(&self) -> Vec<Box<dyn Callable>> {
+        let arc = Arc::new(*self);
+        vec![
+            Box::new(MoveTo(Arc::clone(&arc))),
+            Box::new(Offset(Arc::clone(&arc))),
+            Box::new(Rotate(Arc::clone(&arc))),
+        ]
+    }
     fn eq(&self, other: &dyn Instance) -> bool {
         match other.downcast_ref::<Plane>() {
             Some(o) => self == o,
@@ -14,3 +33,115 @@ impl Instance for Plane {
     }
 }
 from_instance_member!(Plane);
+
+#[derive(Clone, Debug, PartialEq)]
+struct MoveTo(Arc<Plane>);
+impl Callable for MoveTo {
+    fn full_name(&self) -> String {
+        "Plane.move_to".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![
+            Signature::new(&["Length", "Length", "Length"], "Plane"),
+            Signature::new(&["Point"], "Plane"),
+        ]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        let origin = point_3d_from_args(args, &span)?;
+        Ok(plane_from_m(
+            point_to_m(origin),
+            dir_to_array(self.0.x()),
+            dir_to_array(self.0.normal()),
+            &span,
+        )?
+        .into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Offset(Arc<Plane>);
+impl Callable for Offset {
+    fn full_name(&self) -> String {
+        "Plane.offset".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length"], "Plane")]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        let distance = match_args!(Length, args, span);
+        let normal = dir_to_array(self.0.normal());
+        Ok(plane_from_m(
+            add(point_to_m(self.0.origin()), scale(normal, distance.m())),
+            dir_to_array(self.0.x()),
+            normal,
+            &span,
+        )?
+        .into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Rotate(Arc<Plane>);
+impl Callable for Rotate {
+    fn full_name(&self) -> String {
+        "Plane.rotate".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Axis", "Angle"], "Plane")]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        let (axis, angle) = match_args!(Axis::<3>, Angle, args, span);
+        let axis_origin = point_to_m(axis.origin);
+        let axis_direction = dir_to_array(axis.direction);
+
+        let relative_origin = subtract(point_to_m(self.0.origin()), axis_origin);
+        Ok(plane_from_m(
+            add(axis_origin, rotate(relative_origin, axis_direction, angle)),
+            rotate(dir_to_array(self.0.x()), axis_direction, angle),
+            rotate(dir_to_array(self.0.normal()), axis_direction, angle),
+            &span,
+        )?
+        .into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn move_to() {
+        assert_eq!(
+            eval_str("Plane.XY().move_to(1m, 2m, 3m)"),
+            eval_str("Plane(1m, 2m, 3m, 0, 0, 1)")
+        )
+    }
+
+    #[test]
+    fn offset() {
+        assert_eq!(
+            eval_str("Plane.XY().offset(10mm)"),
+            eval_str("Plane(0m, 0m, 10mm, 0, 0, 1)")
+        )
+    }
+
+    #[test]
+    fn rotate() {
+        let plane = eval_str("Plane.XY().offset(1m).rotate(Axis.X(), 90deg)").unwrap();
+        let plane = plane.downcast_ref::<Plane>().unwrap();
+
+        let origin = point_to_m(plane.origin());
+        let normal = dir_to_array(plane.normal());
+        assert!(
+            subtract(origin, [0., -1., 0.])
+                .iter()
+                .all(|c| c.abs() < 1e-9)
+        );
+        assert!(
+            subtract(normal, [0., -1., 0.])
+                .iter()
+                .all(|c| c.abs() < 1e-9)
+        );
+    }
+}
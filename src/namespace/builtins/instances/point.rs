@@ -0,0 +1,372 @@
+use std::sync::Arc;
+
+use anvil::{Length, Point};
+
+use crate::{
+    Callable, Error, Member, Span, from_instance_member, match_args,
+    namespace::{
+        builtins::{
+            Vec3,
+            geometry::{
+                add, dot, point_2d_from_m, point_2d_to_m, point_from_m, point_to_m, scale, subtract,
+            },
+        },
+        traits::Instance,
+    },
+};
+
+impl Instance for Point<3> {
+    fn methods(&self) -> Vec<Box<dyn Callable>> {
+        let self_clone = *self;
+        vec![
+            Box::new(Add(Arc::new(self_clone))),
+            Box::new(Distance(Arc::new(self_clone))),
+            Box::new(Midpoint(Arc::new(self_clone))),
+            Box::new(Subtract(Arc::new(self_clone))),
+            Box::new(X(Arc::new(self_clone))),
+            Box::new(Y(Arc::new(self_clone))),
+            Box::new(Z(Arc::new(self_clone))),
+        ]
+    }
+    fn eq(&self, other: &dyn Instance) -> bool {
+        match other.downcast_ref::<Point<3>>() {
+            Some(o) => self == o,
+            None => false,
+        }
+    }
+    fn type_name(&self) -> String {
+        "Point".into()
+    }
+}
+from_instance_member!(Point<3>);
+
+impl Instance for Point<2> {
+    fn methods(&self) -> Vec<Box<dyn Callable>> {
+        let self_clone = *self;
+        vec![
+            Box::new(Add2D(Arc::new(self_clone))),
+            Box::new(Distance2D(Arc::new(self_clone))),
+            Box::new(Midpoint2D(Arc::new(self_clone))),
+            Box::new(Subtract2D(Arc::new(self_clone))),
+            Box::new(X2D(Arc::new(self_clone))),
+            Box::new(Y2D(Arc::new(self_clone))),
+        ]
+    }
+    fn eq(&self, other: &dyn Instance) -> bool {
+        match other.downcast_ref::<Point<2>>() {
+            Some(o) => self == o,
+            None => false,
+        }
+    }
+    fn type_name(&self) -> String {
+        "Point".into()
+    }
+}
+from_instance_member!(Point<2>);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Add(Arc<Point<3>>);
+impl Callable for Add {
+    fn full_name(&self) -> String {
+        "Point.add".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let other = match_args!(Vec3, args, span);
+        Ok(point_from_m(add(point_to_m(*self.0), other.to_m())).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Distance(Arc<Point<3>>);
+impl Callable for Distance {
+    fn full_name(&self) -> String {
+        "Point.distance".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let other = match_args!(Point::<3>, args, span);
+        let difference = subtract(point_to_m(*self.0), point_to_m(*other));
+        Ok(Length::from_m(dot(difference, difference).sqrt()).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Midpoint(Arc<Point<3>>);
+impl Callable for Midpoint {
+    fn full_name(&self) -> String {
+        "Point.midpoint".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let other = match_args!(Point::<3>, args, span);
+        Ok(point_from_m(scale(add(point_to_m(*self.0), point_to_m(*other)), 0.5)).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Subtract(Arc<Point<3>>);
+impl Callable for Subtract {
+    fn full_name(&self) -> String {
+        "Point.subtract".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let this = point_to_m(*self.0);
+        match args {
+            [other] if other.downcast_ref::<Point<3>>().is_some() => {
+                let other = other.downcast_ref::<Point<3>>().unwrap();
+                Ok(Vec3::from_m(subtract(this, point_to_m(*other))).into())
+            }
+            _ => {
+                let other = match_args!(Vec3, args, span);
+                Ok(point_from_m(subtract(this, other.to_m())).into())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct X(Arc<Point<3>>);
+impl Callable for X {
+    fn full_name(&self) -> String {
+        "Point.x".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        match_args!(args, span);
+        Ok(self.0.x().into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Y(Arc<Point<3>>);
+impl Callable for Y {
+    fn full_name(&self) -> String {
+        "Point.y".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        match_args!(args, span);
+        Ok(self.0.y().into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Z(Arc<Point<3>>);
+impl Callable for Z {
+    fn full_name(&self) -> String {
+        "Point.z".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        match_args!(args, span);
+        Ok(self.0.z().into())
+    }
+}
+
+/// Return the x and y components of a vector that is used to move a 2D point.
+fn vector_2d_to_m(vector: &Vec3, span: &Span) -> Result<[f64; 2], Error> {
+    let [x, y, z] = vector.to_m();
+    if z != 0. {
+        return Err(Error::InvalidArgument(
+            "a 2D point can only be moved by a vector without a z component".into(),
+            span.clone(),
+        ));
+    }
+    Ok([x, y])
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Add2D(Arc<Point<2>>);
+impl Callable for Add2D {
+    fn full_name(&self) -> String {
+        "Point.add".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let other = match_args!(Vec3, args, span);
+        let [x, y] = point_2d_to_m(*self.0);
+        let [dx, dy] = vector_2d_to_m(other, &span)?;
+        Ok(point_2d_from_m([x + dx, y + dy]).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Distance2D(Arc<Point<2>>);
+impl Callable for Distance2D {
+    fn full_name(&self) -> String {
+        "Point.distance".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let other = match_args!(Point::<2>, args, span);
+        let [x1, y1] = point_2d_to_m(*self.0);
+        let [x2, y2] = point_2d_to_m(*other);
+        Ok(Length::from_m((x1 - x2).hypot(y1 - y2)).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Midpoint2D(Arc<Point<2>>);
+impl Callable for Midpoint2D {
+    fn full_name(&self) -> String {
+        "Point.midpoint".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let other = match_args!(Point::<2>, args, span);
+        let [x1, y1] = point_2d_to_m(*self.0);
+        let [x2, y2] = point_2d_to_m(*other);
+        Ok(point_2d_from_m([(x1 + x2) / 2., (y1 + y2) / 2.]).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Subtract2D(Arc<Point<2>>);
+impl Callable for Subtract2D {
+    fn full_name(&self) -> String {
+        "Point.subtract".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let [x, y] = point_2d_to_m(*self.0);
+        match args {
+            [other] if other.downcast_ref::<Point<2>>().is_some() => {
+                let [x2, y2] = point_2d_to_m(*other.downcast_ref::<Point<2>>().unwrap());
+                Ok(Vec3::from_m([x - x2, y - y2, 0.]).into())
+            }
+            _ => {
+                let other = match_args!(Vec3, args, span);
+                let [dx, dy] = vector_2d_to_m(other, &span)?;
+                Ok(point_2d_from_m([x - dx, y - dy]).into())
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct X2D(Arc<Point<2>>);
+impl Callable for X2D {
+    fn full_name(&self) -> String {
+        "Point.x".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        match_args!(args, span);
+        Ok(self.0.x().into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Y2D(Arc<Point<2>>);
+impl Callable for Y2D {
+    fn full_name(&self) -> String {
+        "Point.y".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        match_args!(args, span);
+        Ok(self.0.y().into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::{IntoLength, point};
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            eval_str("Point(1m, 2m, 3m) + Vec3(1m, 1m, 1m)"),
+            Ok(point!(2.m(), 3.m(), 4.m()).into())
+        )
+    }
+
+    #[test]
+    fn add_2d() {
+        assert_eq!(
+            eval_str("Point(1m, 2m) + Vec3(1m, 1m, 0m)"),
+            Ok(point!(2.m(), 3.m()).into())
+        )
+    }
+
+    #[test]
+    fn add_2d_with_z_component() {
+        let input = "Point(1m, 2m) + Vec3(1m, 1m, 1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "a 2D point can only be moved by a vector without a z component".into(),
+                Span::from((0, 32, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn components() {
+        assert_eq!(eval_str("Point(1m, 2m, 3m).x()"), Ok(1.m().into()));
+        assert_eq!(eval_str("Point(1m, 2m, 3m).y()"), Ok(2.m().into()));
+        assert_eq!(eval_str("Point(1m, 2m, 3m).z()"), Ok(3.m().into()));
+    }
+
+    #[test]
+    fn components_2d() {
+        assert_eq!(eval_str("Point(1m, 2m).x()"), Ok(1.m().into()));
+        assert_eq!(eval_str("Point(1m, 2m).y()"), Ok(2.m().into()));
+    }
+
+    #[test]
+    fn distance() {
+        assert_eq!(
+            eval_str("Point(1m, 1m, 1m).distance(Point(4m, 5m, 1m))"),
+            Ok(5.m().into())
+        )
+    }
+
+    #[test]
+    fn distance_2d() {
+        assert_eq!(
+            eval_str("Point(1m, 1m).distance(Point(4m, 5m))"),
+            Ok(5.m().into())
+        )
+    }
+
+    #[test]
+    fn midpoint() {
+        assert_eq!(
+            eval_str("Point(0m, 2m, 4m).midpoint(Point(2m, 4m, 6m))"),
+            Ok(point!(1.m(), 3.m(), 5.m()).into())
+        )
+    }
+
+    #[test]
+    fn midpoint_2d() {
+        assert_eq!(
+            eval_str("Point(0m, 2m).midpoint(Point(2m, 4m))"),
+            Ok(point!(1.m(), 3.m()).into())
+        )
+    }
+
+    #[test]
+    fn subtract_point() {
+        assert_eq!(
+            eval_str("Point(3m, 3m, 3m) - Point(1m, 2m, 3m)"),
+            Ok(Vec3(2.m(), 1.m(), 0.m()).into())
+        )
+    }
+
+    #[test]
+    fn subtract_point_2d() {
+        assert_eq!(
+            eval_str("Point(3m, 3m) - Point(1m, 2m)"),
+            Ok(Vec3(2.m(), 1.m(), 0.m()).into())
+        )
+    }
+
+    #[test]
+    fn subtract_vector_2d() {
+        assert_eq!(
+            eval_str("Point(3m, 3m) - Vec3(1m, 2m, 0m)"),
+            Ok(point!(2.m(), 1.m()).into())
+        )
+    }
+
+    #[test]
+    fn subtract_vector() {
+        assert_eq!(
+            eval_str("Point(3m, 3m, 3m) - Vec3(1m, 2m, 3m)"),
+            Ok(point!(2.m(), 1.m(), 0.m()).into())
+        )
+    }
+}
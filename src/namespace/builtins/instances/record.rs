@@ -0,0 +1,100 @@
+use crate::{Member, from_instance_member, namespace::traits::Instance};
+
+/// A group of named values, like `{d: 3mm, head_d: 5.5mm}`.
+///
+/// Fields are accessed with the `.` syntax, e.g. `screw.d`, and keep the order of the literal.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Record(pub Vec<(String, Member)>);
+impl Record {
+    /// Return the value of the field with the given name.
+    pub fn get(&self, name: &str) -> Option<&Member> {
+        self.0
+            .iter()
+            .find(|(field, _)| field == name)
+            .map(|(_, value)| value)
+    }
+}
+
+impl Instance for Record {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        match other.downcast_ref::<Record>() {
+            Some(o) => self == o,
+            None => false,
+        }
+    }
+    fn type_name(&self) -> String {
+        "Record".into()
+    }
+    fn display(&self) -> String {
+        let fields: Vec<String> = self
+            .0
+            .iter()
+            .map(|(name, value)| format!("{}: {}", name, value.display()))
+            .collect();
+        format!("{{{}}}", fields.join(", "))
+    }
+}
+from_instance_member!(Record);
+
+#[cfg(test)]
+mod tests {
+    use anvil::IntoLength;
+
+    use super::*;
+    use crate::{Error, Span, eval_str};
+
+    #[test]
+    fn literal() {
+        assert_eq!(
+            eval_str("{d: 3mm, head_d: 5.5mm}"),
+            Ok(Record(vec![
+                ("d".into(), 3.mm().into()),
+                ("head_d".into(), 5.5.mm().into())
+            ])
+            .into())
+        )
+    }
+
+    #[test]
+    fn field_access() {
+        assert_eq!(
+            eval_str("{d: 3mm, head_d: 5.5mm}.head_d"),
+            Ok(5.5.mm().into())
+        )
+    }
+
+    #[test]
+    fn nested_field_access() {
+        assert_eq!(eval_str("{screw: {d: 3mm}}.screw.d * 2"), Ok(6.mm().into()))
+    }
+
+    #[test]
+    fn unknown_field() {
+        let input = "{d: 3mm}.length";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::UnknownField(
+                "length".into(),
+                Span::from((0, 15, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn duplicate_field() {
+        let input = "{d: 3mm, d: 4mm}";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "field d is defined more than once".into(),
+                Span::from((0, 16, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn display() {
+        let record = Record(vec![("d".into(), 3.mm().into()), ("n".into(), 2.0.into())]);
+        assert_eq!(Instance::display(&record), "{d: 3mm, n: 2}")
+    }
+}
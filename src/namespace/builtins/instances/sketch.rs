@@ -1,9 +1,16 @@
 use std::rc::Rc;
 
-use anvil::{Length, Plane, Sketch, point};
+use anvil::{Length, Plane, Sketch};
 
 use crate::{
-    Callable, Error, Member, from_instance_member, match_args, namespace::traits::Instance,
+    Callable, Error, Member, Signature, from_instance_member, match_args,
+    namespace::{
+        builtins::{
+            geometry::{point_2d_from_args, shape_operand},
+            instances::area::Area,
+        },
+        traits::Instance,
+    },
 };
 
 impl Instance for Sketch {
@@ -14,6 +21,7 @@ impl Instance for Sketch {
         let rc = Rc::new(self.clone());
         vec![
             Box::new(Add(Rc::clone(&rc))),
+            Box::new(AreaMethod(Rc::clone(&rc))),
             Box::new(Extrude(Rc::clone(&rc))),
             Box::new(Intersect(Rc::clone(&rc))),
             Box::new(MoveTo(Rc::clone(&rc))),
@@ -35,18 +43,39 @@ impl Callable for Add {
     fn full_name(&self) -> String {
         "Sketch.add".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Sketch"], "Sketch")]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(Sketch, args, span);
+        let other = shape_operand::<Sketch>("add", "Sketch", args, &span)?;
         Ok((self.0.add(other)).into())
     }
 }
 
+#[derive(Clone, Debug, PartialEq)]
+struct AreaMethod(Rc<Sketch>);
+impl Callable for AreaMethod {
+    fn full_name(&self) -> String {
+        "Sketch.area".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Area")]
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        Ok(Area::from_m2(self.0.area()).into())
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 struct Extrude(Rc<Sketch>);
 impl Callable for Extrude {
     fn full_name(&self) -> String {
         "Sketch.extrude".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Plane", "Length"], "Part")]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
         let (plane, thickness) = match_args!(Plane, Length, args, span);
         match self.0.extrude(plane, thickness) {
@@ -62,8 +91,11 @@ impl Callable for Intersect {
     fn full_name(&self) -> String {
         "Sketch.intersect".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Sketch"], "Sketch")]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(Sketch, args, span);
+        let other = shape_operand::<Sketch>("intersect", "Sketch", args, &span)?;
         Ok((self.0.intersect(other)).into())
     }
 }
@@ -74,9 +106,15 @@ impl Callable for MoveTo {
     fn full_name(&self) -> String {
         "Sketch.move_to".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![
+            Signature::new(&["Length", "Length"], "Sketch"),
+            Signature::new(&["Point"], "Sketch"),
+        ]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let (x, y) = match_args!(Length, Length, args, span);
-        Ok((self.0.move_to(point!(x, y))).into())
+        let point = point_2d_from_args(args, &span)?;
+        Ok((self.0.move_to(point)).into())
     }
 }
 
@@ -86,8 +124,11 @@ impl Callable for Subtract {
     fn full_name(&self) -> String {
         "Sketch.subtract".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Sketch"], "Sketch")]
+    }
     fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
-        let other = match_args!(Sketch, args, span);
+        let other = shape_operand::<Sketch>("subtract", "Sketch", args, &span)?;
         Ok((self.0.subtract(other)).into())
     }
 }
@@ -97,6 +138,8 @@ mod tests {
     use crate::eval_str;
     use anvil::{IntoLength, Plane, Rectangle, point};
 
+    use super::*;
+
     #[test]
     fn add() {
         assert_eq!(
@@ -107,6 +150,14 @@ mod tests {
         )
     }
 
+    #[test]
+    fn area() {
+        assert_eq!(
+            eval_str("Rectangle(2m, 3m).area()"),
+            Ok(Area::from_m2(6.).into())
+        )
+    }
+
     #[test]
     fn extrude() {
         assert_eq!(
@@ -138,6 +189,16 @@ mod tests {
         )
     }
 
+    #[test]
+    fn move_to_point() {
+        assert_eq!(
+            eval_str("Rectangle(1m, 2m).move_to(Point(3m, 4m))"),
+            Ok(Rectangle::from_dim(1.m(), 2.m())
+                .move_to(point!(3.m(), 4.m()))
+                .into())
+        )
+    }
+
     #[test]
     fn subtract() {
         assert_eq!(
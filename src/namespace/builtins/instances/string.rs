@@ -0,0 +1,166 @@
+use std::rc::Rc;
+
+use crate::{
+    Callable, Error, Member, Signature, Span, from_instance_member, match_args,
+    namespace::traits::Instance,
+};
+
+impl Instance for String {
+    fn methods(&self) -> Vec<Box<dyn Callable>> {
+        let rc = Rc::new(self.clone());
+        vec![Box::new(Add(Rc::clone(&rc))), Box::new(Len(Rc::clone(&rc)))]
+    }
+    fn eq(&self, other: &dyn Instance) -> bool {
+        match other.downcast_ref::<String>() {
+            Some(o) => self == o,
+            None => false,
+        }
+    }
+    fn type_name(&self) -> String {
+        "String".into()
+    }
+    fn display(&self) -> String {
+        self.clone()
+    }
+}
+from_instance_member!(String);
+
+/// Concatenate two strings, which is what `a + b` evaluates to.
+#[derive(Clone, Debug, PartialEq)]
+struct Add(Rc<String>);
+impl Callable for Add {
+    fn full_name(&self) -> String {
+        "String.add".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["String"], "String")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        match args {
+            [other] => match other.downcast_ref::<String>() {
+                Some(other) => Ok(format!("{}{}", self.0, other).into()),
+                None => Err(Error::IncompatibleOperands {
+                    operation: "add".into(),
+                    left: "String".into(),
+                    right: other.type_name(),
+                    span,
+                }),
+            },
+            _ => Err(Error::Arguments {
+                should: vec!["String".into()],
+                is: args.iter().map(|arg| arg.type_name()).collect(),
+                span,
+            }),
+        }
+    }
+}
+
+/// Return the number of characters in a string.
+#[derive(Clone, Debug, PartialEq)]
+struct Len(Rc<String>);
+impl Callable for Len {
+    fn full_name(&self) -> String {
+        "String.len".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Number")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        match_args!(args, span);
+        Ok((self.0.chars().count() as f64).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn literal() {
+        assert_eq!(
+            eval_str(r#""front plate""#),
+            Ok("front plate".to_string().into())
+        )
+    }
+
+    #[test]
+    fn escapes() {
+        assert_eq!(
+            eval_str(r#""say \"hi\"\n\tand \\ leave""#),
+            Ok("say \"hi\"\n\tand \\ leave".to_string().into())
+        )
+    }
+
+    #[test]
+    fn concatenation() {
+        assert_eq!(eval_str(r#""a" + "b""#), Ok("ab".to_string().into()))
+    }
+
+    #[test]
+    fn concatenation_with_number() {
+        let input = r#""a" + 1"#;
+        assert_eq!(
+            eval_str(input),
+            Err(Error::IncompatibleOperands {
+                operation: "add".into(),
+                left: "String".into(),
+                right: "Number".into(),
+                span: Span::from((0, 7, input))
+            })
+        )
+    }
+
+    #[test]
+    fn len() {
+        assert_eq!(eval_str(r#""läng".len()"#), Ok(4.0.into()))
+    }
+
+    #[test]
+    fn format_string() {
+        assert_eq!(
+            eval_str(r#"f"width={2 * 3mm}, count={1 + 1}""#),
+            Ok("width=6mm, count=2".to_string().into())
+        )
+    }
+
+    #[test]
+    fn format_string_with_escaped_braces() {
+        assert_eq!(
+            eval_str(r#"f"{{x}} = {[1, 2]}""#),
+            Ok("{x} = [1, 2]".to_string().into())
+        )
+    }
+
+    #[test]
+    fn format_string_with_record() {
+        assert_eq!(eval_str(r#"f"{ {a: 1}.a }""#), Ok("1".to_string().into()))
+    }
+
+    #[test]
+    fn format_string_with_nested_string() {
+        assert_eq!(eval_str(r#"f"{"}"}{f"{1}"}""#), Ok("}1".to_string().into()))
+    }
+
+    #[test]
+    fn format_string_with_empty_expression() {
+        let input = r#"f"a{}""#;
+        assert_eq!(
+            eval_str(input),
+            Err(Error::ExpectedExpression(Span::from((3, 5, input))))
+        )
+    }
+
+    #[test]
+    fn format_string_with_unknown_variable() {
+        let input = r#"f"w={width}""#;
+        assert_eq!(
+            eval_str(input),
+            Err(Error::UnknownVariable(
+                "width".into(),
+                None,
+                Span::from((5, 10, input))
+            ))
+        )
+    }
+}
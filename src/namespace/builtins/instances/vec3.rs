@@ -0,0 +1,220 @@
+use std::sync::Arc;
+
+use anvil::Length;
+
+use crate::{
+    Callable, Member, from_instance_member, match_args,
+    namespace::{
+        builtins::geometry::{add, dot, scale, subtract},
+        quantity::division_by_zero,
+        traits::Instance,
+    },
+};
+
+/// A displacement in 3D space.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Vec3(pub Length, pub Length, pub Length);
+impl Vec3 {
+    /// Return the components of this vector in meters.
+    pub fn to_m(&self) -> [f64; 3] {
+        [self.0.m(), self.1.m(), self.2.m()]
+    }
+    /// Return a vector from components in meters.
+    pub fn from_m(components: [f64; 3]) -> Self {
+        Vec3(
+            Length::from_m(components[0]),
+            Length::from_m(components[1]),
+            Length::from_m(components[2]),
+        )
+    }
+}
+
+impl Instance for Vec3 {
+    fn methods(&self) -> Vec<Box<dyn Callable>> {
+        let self_clone = *self;
+        vec![
+            Box::new(Add(Arc::new(self_clone))),
+            Box::new(Divide(Arc::new(self_clone))),
+            Box::new(LengthMethod(Arc::new(self_clone))),
+            Box::new(Multiply(Arc::new(self_clone))),
+            Box::new(Subtract(Arc::new(self_clone))),
+            Box::new(X(Arc::new(self_clone))),
+            Box::new(Y(Arc::new(self_clone))),
+            Box::new(Z(Arc::new(self_clone))),
+        ]
+    }
+    fn eq(&self, other: &dyn Instance) -> bool {
+        match other.downcast_ref::<Vec3>() {
+            Some(o) => self == o,
+            None => false,
+        }
+    }
+    fn type_name(&self) -> String {
+        "Vec3".into()
+    }
+}
+from_instance_member!(Vec3);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Add(Arc<Vec3>);
+impl Callable for Add {
+    fn full_name(&self) -> String {
+        "Vec3.add".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        let other = match_args!(Vec3, args, span);
+        Ok(Vec3::from_m(add(self.0.to_m(), other.to_m())).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Divide(Arc<Vec3>);
+impl Callable for Divide {
+    fn full_name(&self) -> String {
+        "Vec3.divide".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        let other = match_args!(f64, args, span);
+        if *other == 0. {
+            return Err(division_by_zero(span));
+        }
+        Ok(Vec3::from_m(scale(self.0.to_m(), 1. / *other)).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct LengthMethod(Arc<Vec3>);
+impl Callable for LengthMethod {
+    fn full_name(&self) -> String {
+        "Vec3.length".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        let components = self.0.to_m();
+        Ok(Length::from_m(dot(components, components).sqrt()).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Multiply(Arc<Vec3>);
+impl Callable for Multiply {
+    fn full_name(&self) -> String {
+        "Vec3.multiply".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        let other = match_args!(f64, args, span);
+        Ok(Vec3::from_m(scale(self.0.to_m(), *other)).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Subtract(Arc<Vec3>);
+impl Callable for Subtract {
+    fn full_name(&self) -> String {
+        "Vec3.subtract".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        let other = match_args!(Vec3, args, span);
+        Ok(Vec3::from_m(subtract(self.0.to_m(), other.to_m())).into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct X(Arc<Vec3>);
+impl Callable for X {
+    fn full_name(&self) -> String {
+        "Vec3.x".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        Ok(self.0.0.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Y(Arc<Vec3>);
+impl Callable for Y {
+    fn full_name(&self) -> String {
+        "Vec3.y".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        Ok(self.0.1.into())
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+struct Z(Arc<Vec3>);
+impl Callable for Z {
+    fn full_name(&self) -> String {
+        "Vec3.z".into()
+    }
+    fn call(&self, args: &[Member], span: crate::Span) -> Result<Member, crate::Error> {
+        match_args!(args, span);
+        Ok(self.0.2.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::IntoLength;
+
+    use super::*;
+    use crate::{Error, Span, eval_str};
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            eval_str("Vec3(1m, 2m, 3m) + Vec3(1m, 1m, 1m)"),
+            Ok(Vec3(2.m(), 3.m(), 4.m()).into())
+        )
+    }
+
+    #[test]
+    fn divide() {
+        assert_eq!(
+            eval_str("Vec3(2m, 4m, 6m) / 2"),
+            Ok(Vec3(1.m(), 2.m(), 3.m()).into())
+        )
+    }
+
+    #[test]
+    fn divide_by_zero() {
+        let input = "Vec3(2m, 4m, 6m) / 0";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "division by zero".into(),
+                Span::from((0, 20, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(eval_str("Vec3(3m, 4m, 0m).length()"), Ok(5.m().into()))
+    }
+
+    #[test]
+    fn multiply() {
+        assert_eq!(
+            eval_str("Vec3(1m, 2m, 3m) * 2"),
+            Ok(Vec3(2.m(), 4.m(), 6.m()).into())
+        )
+    }
+
+    #[test]
+    fn subtract() {
+        assert_eq!(
+            eval_str("Vec3(1m, 2m, 3m) - Vec3(1m, 1m, 1m)"),
+            Ok(Vec3(0.m(), 1.m(), 2.m()).into())
+        )
+    }
+
+    #[test]
+    fn components() {
+        assert_eq!(eval_str("Vec3(1m, 2m, 3m).x()"), Ok(1.m().into()));
+        assert_eq!(eval_str("Vec3(1m, 2m, 3m).y()"), Ok(2.m().into()));
+        assert_eq!(eval_str("Vec3(1m, 2m, 3m).z()"), Ok(3.m().into()));
+    }
+}
@@ -0,0 +1,55 @@
+use crate::length_power;
+
+length_power!(
+    /// A cubic quantity, like the volume of a part.
+    Volume,
+    "Volume",
+    3,
+    "cubic",
+    (from_m3, from_cm3, from_mm3),
+    (m3, cm3, mm3)
+);
+
+#[cfg(test)]
+mod tests {
+    use super::Volume;
+    use crate::eval_str;
+
+    #[test]
+    fn add() {
+        assert_eq!(
+            eval_str("Cube(1m).volume() + Cube(2m).volume()"),
+            Ok(Volume::from_m3(9.).into())
+        )
+    }
+
+    #[test]
+    fn divide() {
+        assert_eq!(
+            eval_str("Cube(2m).volume() / 4"),
+            Ok(Volume::from_m3(2.).into())
+        )
+    }
+
+    #[test]
+    fn multiply() {
+        assert_eq!(
+            eval_str("Cube(2m).volume() * 2"),
+            Ok(Volume::from_m3(16.).into())
+        )
+    }
+
+    #[test]
+    fn subtract() {
+        assert_eq!(
+            eval_str("Cube(2m).volume() - Cube(1m).volume()"),
+            Ok(Volume::from_m3(7.).into())
+        )
+    }
+
+    #[test]
+    fn unit_conversion() {
+        assert_eq!(Volume::from_cm3(1.).mm3(), 1000.);
+        assert_eq!(Volume::from_mm3(1e9).m3(), 1.);
+    }
+}
@@ -0,0 +1,474 @@
+use anvil::Angle;
+
+use crate::{
+    Callable, Error, Member, Span, from_type_member, match_args,
+    namespace::{
+        quantity::Quantity,
+        traits::{Instance, Type},
+    },
+};
+
+/// Define a unit struct for a builtin function that can be stored in the namespace.
+macro_rules! math_function {
+    ($name:ident, $full_name:literal) => {
+        #[derive(Clone, Debug, PartialEq)]
+        pub struct $name;
+        impl Type for $name {}
+        impl Instance for $name {
+            fn eq(&self, other: &dyn Instance) -> bool {
+                other.downcast_ref::<Self>().is_some()
+            }
+            fn type_name(&self) -> String {
+                "Function".into()
+            }
+        }
+        from_type_member!($name);
+        impl $name {
+            const NAME: &str = $full_name;
+        }
+    };
+}
+
+math_function!(Abs, "abs");
+impl Callable for Abs {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let [x] = quantities::<1>(args, &span)?;
+        to_member(x.with_value(x.value().abs()))
+    }
+}
+
+math_function!(Atan2, "atan2");
+impl Callable for Atan2 {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let [y, x] = quantities::<2>(args, &span)?;
+        Ok(Angle::from_rad(y.value().atan2(x.value())).into())
+    }
+}
+
+math_function!(Ceil, "ceil");
+impl Callable for Ceil {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        round_with(args, span, "ceil", f64::ceil)
+    }
+}
+
+math_function!(Clamp, "clamp");
+impl Callable for Clamp {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let [x, min, max] = quantities::<3>(args, &span)?;
+        if min.value() > max.value() {
+            return Err(Error::InvalidArgument(
+                "the lower bound of clamp can not be larger than the upper bound".into(),
+                span,
+            ));
+        }
+        to_member(x.with_value(x.value().clamp(min.value(), max.value())))
+    }
+}
+
+math_function!(Cos, "cos");
+impl Callable for Cos {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let angle = match_args!(Angle, args, span);
+        Ok(angle.rad().cos().into())
+    }
+}
+
+math_function!(Floor, "floor");
+impl Callable for Floor {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        round_with(args, span, "floor", f64::floor)
+    }
+}
+
+math_function!(Hypot, "hypot");
+impl Callable for Hypot {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let [a, b] = quantities::<2>(args, &span)?;
+        to_member(a.with_value(a.value().hypot(b.value())))
+    }
+}
+
+math_function!(Max, "max");
+impl Callable for Max {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let values = variadic_quantities(args, &span)?;
+        let max = values
+            .iter()
+            .map(|q| q.value())
+            .fold(f64::NEG_INFINITY, f64::max);
+        to_member(values[0].with_value(max))
+    }
+}
+
+math_function!(Min, "min");
+impl Callable for Min {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let values = variadic_quantities(args, &span)?;
+        let min = values
+            .iter()
+            .map(|q| q.value())
+            .fold(f64::INFINITY, f64::min);
+        to_member(values[0].with_value(min))
+    }
+}
+
+math_function!(Round, "round");
+impl Callable for Round {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        round_with(args, span, "round", f64::round)
+    }
+}
+
+math_function!(Sin, "sin");
+impl Callable for Sin {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let angle = match_args!(Angle, args, span);
+        Ok(angle.rad().sin().into())
+    }
+}
+
+math_function!(Sqrt, "sqrt");
+impl Callable for Sqrt {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let [x] = quantities::<1>(args, &span)?;
+        if x.value() < 0. {
+            return Err(Error::InvalidArgument(
+                "can not take the square root of a negative value".into(),
+                span,
+            ));
+        }
+        match x.powf(0.5).and_then(Quantity::to_member) {
+            Some(member) => Ok(member),
+            None => Err(Error::InvalidArgument(
+                format!("the square root of {} has no supported unit", x.type_name()),
+                span,
+            )),
+        }
+    }
+}
+
+math_function!(Tan, "tan");
+impl Callable for Tan {
+    fn full_name(&self) -> String {
+        Self::NAME.into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let angle = match_args!(Angle, args, span);
+        Ok(angle.rad().tan().into())
+    }
+}
+
+/// Round a value with the given function.
+///
+/// Numbers are rounded to whole numbers. Values with a unit need a step as second argument (like
+/// `round(x, 1mm)`), as rounding them to whole SI units is rarely intended. The step has to be
+/// larger than zero, so that `floor` and `ceil` keep rounding down and up.
+fn round_with(args: &[Member], span: Span, name: &str, f: fn(f64) -> f64) -> Result<Member, Error> {
+    if args.len() == 2 {
+        let [x, step] = quantities::<2>(args, &span)?;
+        if step.value() <= 0. {
+            return Err(Error::InvalidArgument(
+                format!("the step of {} has to be larger than zero", name),
+                span,
+            ));
+        }
+        return to_member(x.with_value(f(x.value() / step.value()) * step.value()));
+    }
+
+    let [x] = quantities::<1>(args, &span)?;
+    if x.to_member()
+        .is_some_and(|m| m.downcast_ref::<f64>().is_none())
+    {
+        return Err(Error::InvalidArgument(
+            format!(
+                "rounding a {} requires a step as second argument, like {}(x, 0.1mm)",
+                x.type_name(),
+                name
+            ),
+            span,
+        ));
+    }
+    to_member(x.with_value(f(x.value())))
+}
+
+/// Return the quantities of exactly N arguments that all have the same dimensions.
+fn quantities<const N: usize>(args: &[Member], span: &Span) -> Result<[Quantity; N], Error> {
+    if args.len() != N {
+        return Err(arguments_error(args, N, span));
+    }
+    let values = variadic_quantities(args, span)?;
+    Ok(std::array::from_fn(|i| values[i]))
+}
+
+/// Return the quantities of one or more arguments that all have the same dimensions.
+fn variadic_quantities(args: &[Member], span: &Span) -> Result<Vec<Quantity>, Error> {
+    let values: Option<Vec<Quantity>> = args.iter().map(Quantity::from_member).collect();
+    match values {
+        Some(values)
+            if !values.is_empty() && values.iter().all(|q| q.has_dimension_of(&values[0])) =>
+        {
+            Ok(values)
+        }
+        _ => Err(arguments_error(args, args.len().max(1), span)),
+    }
+}
+
+fn arguments_error(args: &[Member], count: usize, span: &Span) -> Error {
+    let should = match args.first().and_then(Quantity::from_member) {
+        Some(first) => first.type_name(),
+        None => "Number".into(),
+    };
+    Error::Arguments {
+        should: vec![should; count],
+        is: args.iter().map(|arg| arg.type_name()).collect(),
+        span: span.clone(),
+    }
+}
+
+fn to_member(quantity: Quantity) -> Result<Member, Error> {
+    Ok(quantity
+        .to_member()
+        .expect("results with the dimensions of the arguments are representable"))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use anvil::{IntoAngle, IntoLength, Length};
+
+    use super::*;
+    use crate::{Area, eval_str};
+
+    fn assert_number(input: &str, expected: f64) {
+        let result = eval_str(input).unwrap();
+        let number = result.downcast_ref::<f64>().unwrap();
+        assert!((number - expected).abs() < 1e-12, "{input}: {number}");
+    }
+
+    fn assert_length(input: &str, meters: f64) {
+        let result = eval_str(input).unwrap();
+        let length = result.downcast_ref::<Length>().unwrap();
+        assert!((length.m() - meters).abs() < 1e-12, "{input}: {length:?}");
+    }
+
+    #[test]
+    fn abs() {
+        assert_eq!(eval_str("abs(-3m)"), Ok(3.m().into()))
+    }
+
+    #[test]
+    fn atan2() {
+        let result = eval_str("atan2(1m, 1m)").unwrap();
+        let angle = result.downcast_ref::<Angle>().unwrap();
+        assert!((angle.rad() - PI / 4.).abs() < 1e-12)
+    }
+
+    #[test]
+    fn ceil() {
+        assert_eq!(eval_str("ceil(2.1)"), Ok(3.0.into()))
+    }
+
+    #[test]
+    fn ceil_with_step() {
+        assert_length("ceil(2.1mm, 0.5mm)", 0.0025)
+    }
+
+    #[test]
+    fn clamp() {
+        assert_eq!(eval_str("clamp(5m, 1m, 3m)"), Ok(3.m().into()))
+    }
+
+    #[test]
+    fn clamp_invalid_bounds() {
+        let input = "clamp(5m, 3m, 1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the lower bound of clamp can not be larger than the upper bound".into(),
+                Span::from((0, 17, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn cos() {
+        assert_number("cos(60deg)", 0.5)
+    }
+
+    #[test]
+    fn floor() {
+        assert_eq!(eval_str("floor(2.9)"), Ok(2.0.into()))
+    }
+
+    #[test]
+    fn hypot() {
+        assert_eq!(eval_str("hypot(3m, 4m)"), Ok(5.m().into()))
+    }
+
+    #[test]
+    fn max() {
+        assert_eq!(eval_str("max(1m, 3m, 2m)"), Ok(3.m().into()))
+    }
+
+    #[test]
+    fn min() {
+        assert_eq!(eval_str("min(2m, 1m)"), Ok(1.m().into()))
+    }
+
+    #[test]
+    fn min_of_mixed_dimensions() {
+        let input = "min(2m, 1deg)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::Arguments {
+                should: vec!["Length".into(), "Length".into()],
+                is: vec!["Length".into(), "Angle".into()],
+                span: Span::from((0, 13, input))
+            })
+        )
+    }
+
+    #[test]
+    fn round() {
+        assert_eq!(eval_str("round(2.5)"), Ok(3.0.into()))
+    }
+
+    #[test]
+    fn round_length_without_step() {
+        let input = "round(2.4mm)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "rounding a Length requires a step as second argument, like round(x, 0.1mm)".into(),
+                Span::from((0, 12, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn round_with_zero_step() {
+        let input = "round(2.4mm, 0mm)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the step of round has to be larger than zero".into(),
+                Span::from((0, 17, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn floor_with_negative_step() {
+        let input = "floor(2.4mm, -1mm)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the step of floor has to be larger than zero".into(),
+                Span::from((0, 18, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn sin() {
+        assert_number("sin(30deg)", 0.5)
+    }
+
+    #[test]
+    fn sqrt() {
+        assert_eq!(eval_str("sqrt(9)"), Ok(3.0.into()))
+    }
+
+    #[test]
+    fn sqrt_of_area() {
+        assert_eq!(eval_str("sqrt(3m * 12m)"), Ok(6.m().into()))
+    }
+
+    #[test]
+    fn sqrt_of_length() {
+        let input = "sqrt(4m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the square root of Length has no supported unit".into(),
+                Span::from((0, 8, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn tan() {
+        assert_number("tan(45deg)", 1.)
+    }
+
+    #[test]
+    fn tan_of_number() {
+        let input = "tan(1)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::Arguments {
+                should: vec!["Angle".into()],
+                is: vec!["Number".into()],
+                span: Span::from((0, 6, input))
+            })
+        )
+    }
+
+    #[test]
+    fn constants() {
+        assert_eq!(eval_str("pi"), Ok(PI.into()));
+        assert_eq!(eval_str("tau"), Ok((2. * PI).into()));
+        assert_eq!(eval_str("pi * 1rad"), Ok(PI.rad().into()));
+    }
+
+    #[test]
+    fn hexagon_across_corners() {
+        assert_length("10mm / cos(30deg)", 0.01 / (PI / 6.).cos())
+    }
+
+    #[test]
+    fn area_stays_area() {
+        assert_eq!(
+            eval_str("max(1m * 1m, 2m * 2m)"),
+            Ok(Area::from_m2(4.).into())
+        )
+    }
+}
@@ -1,6 +1,24 @@
+mod geometry;
 mod instances;
+pub mod math;
+pub mod print;
 mod types;
 
+pub use instances::area::Area;
+pub use instances::bounding_box::BoundingBox;
+pub use instances::list::List;
+pub use instances::record::Record;
+pub use instances::vec3::Vec3;
+pub use instances::volume::Volume;
 pub use types::axis::AxisType;
+pub use types::ellipse::EllipseType;
 pub use types::path::PathType;
 pub use types::plane::PlaneType;
+pub use types::point::PointType;
+pub use types::polygon::PolygonType;
+pub use types::prism::PrismType;
+pub use types::regular_polygon::RegularPolygonType;
+pub use types::rounded_rectangle::RoundedRectangleType;
+pub use types::slot::SlotType;
+pub use types::vec3::Vec3Type;
+pub use types::wedge::WedgeType;
@@ -0,0 +1,45 @@
+use std::{cell::RefCell, rc::Rc};
+
+use crate::{
+    Callable, Error, Member, Span, from_type_member,
+    namespace::traits::{Instance, Type},
+};
+
+/// The builtin `print` function.
+///
+/// Instead of writing to stdout, printed lines are collected in a sink that is shared with the
+/// `PartNamespace` this function belongs to, so that hosts can decide where to show them.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Print(Rc<RefCell<Vec<String>>>);
+impl Print {
+    /// Return a print function that appends its lines to the given sink.
+    pub fn new(sink: Rc<RefCell<Vec<String>>>) -> Self {
+        Self(sink)
+    }
+}
+impl Type for Print {}
+impl Instance for Print {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Function".into()
+    }
+}
+from_type_member!(Print);
+
+impl Callable for Print {
+    fn full_name(&self) -> String {
+        "print".into()
+    }
+    /// Print all arguments separated by a space and return the printed line.
+    fn call(&self, args: &[Member], _: Span) -> Result<Member, Error> {
+        let line = args
+            .iter()
+            .map(Member::display)
+            .collect::<Vec<String>>()
+            .join(" ");
+        self.0.borrow_mut().push(line.clone());
+        Ok(line.into())
+    }
+}
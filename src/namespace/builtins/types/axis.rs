@@ -1,8 +1,11 @@
 use anvil::Axis;
 
 use crate::{
-    from_type_member, match_args,
-    namespace::traits::{Callable, Instance, Type},
+    Error, Member, Signature, Span, from_type_member, match_args,
+    namespace::{
+        builtins::geometry::{axis_from_m, point_and_direction_from_args},
+        traits::{Callable, Instance, Type},
+    },
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -15,6 +18,10 @@ impl Callable for AxisType {
     fn full_name(&self) -> String {
         "Axis".into()
     }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (origin, direction) = point_and_direction_from_args(args, &span)?;
+        Ok(axis_from_m(origin, direction, &span)?.into())
+    }
 }
 
 impl Instance for AxisType {
@@ -35,6 +42,9 @@ impl Callable for X {
     fn full_name(&self) -> String {
         "Axis.X".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Axis")]
+    }
     fn call(
         &self,
         args: &[crate::Member],
@@ -51,6 +61,9 @@ impl Callable for Y {
     fn full_name(&self) -> String {
         "Axis.Y".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Axis")]
+    }
     fn call(
         &self,
         args: &[crate::Member],
@@ -67,6 +80,9 @@ impl Callable for Z {
     fn full_name(&self) -> String {
         "Axis.Z".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Axis")]
+    }
     fn call(
         &self,
         args: &[crate::Member],
@@ -80,7 +96,61 @@ impl Callable for Z {
 #[cfg(test)]
 mod tests {
     use crate::eval_str;
-    use anvil::Axis;
+    use anvil::{Axis, Dir, IntoLength, point};
+
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Axis(1m, 2m, 3m, 0, 0, 2)"),
+            Ok(Axis::<3>::new(
+                point!(1.m(), 2.m(), 3.m()),
+                Dir::try_from([0., 0., 1.]).unwrap()
+            )
+            .into())
+        )
+    }
+
+    #[test]
+    fn construct_zero_direction() {
+        let input = "Axis(1m, 2m, 3m, 0, 0, 0)";
+        assert_eq!(
+            eval_str(input),
+            Err(crate::Error::InvalidArgument(
+                "a direction can not be a zero vector".into(),
+                crate::Span::from((0, 25, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn construct_with_direction_of_lengths() {
+        let input = "Axis(0m, 0m, 0m, 0m, 0m, 1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(crate::Error::Arguments {
+                should: ["Length", "Length", "Length", "Number", "Number", "Number"]
+                    .map(String::from)
+                    .to_vec(),
+                is: ["Length"; 6].map(String::from).to_vec(),
+                span: crate::Span::from((0, 28, input))
+            })
+        )
+    }
+
+    #[test]
+    fn construct_from_point_with_vector() {
+        let input = "Axis(Point(0m, 0m, 0m), Vec3(0m, 0m, 1m))";
+        assert_eq!(
+            eval_str(input),
+            Err(crate::Error::Arguments {
+                should: ["Point", "Number", "Number", "Number"]
+                    .map(String::from)
+                    .to_vec(),
+                is: ["Point", "Vec3"].map(String::from).to_vec(),
+                span: crate::Span::from((0, 41, input))
+            })
+        )
+    }
 
     #[test]
     fn x_constructor() {
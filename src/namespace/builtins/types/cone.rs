@@ -0,0 +1,54 @@
+use anvil::{Cone, Length};
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+impl Type for Cone {}
+from_type_member!(Cone);
+
+impl Callable for Cone {
+    fn full_name(&self) -> String {
+        "Cone".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length", "Length"], "Part")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (bottom_radius, top_radius, height) = match_args!(Length, Length, Length, args, span);
+        Ok(Member::Instance(Box::new(Cone::from_radii(
+            bottom_radius,
+            top_radius,
+            height,
+        ))))
+    }
+}
+
+impl Instance for Cone {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::IntoLength;
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Cone(5m, 2m, 7m)"),
+            Ok(Cone::from_radii(5.m(), 2.m(), 7.m()).into())
+        )
+    }
+}
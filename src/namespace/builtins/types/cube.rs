@@ -1,7 +1,7 @@
 use anvil::{Cube, Length};
 
 use crate::{
-    Error, Span, from_type_member, match_args,
+    Error, Signature, Span, from_type_member, match_args,
     namespace::{
         Member,
         traits::{Callable, Instance, Type},
@@ -15,6 +15,9 @@ impl Callable for Cube {
     fn full_name(&self) -> String {
         "Cube".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length"], "Part")]
+    }
     fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
         let size = match_args!(Length, args, span);
         Ok(Member::Instance(Box::new(Cube::from_size(*size))))
@@ -1,7 +1,7 @@
 use anvil::{Cuboid, Length};
 
 use crate::{
-    Error, Span, from_type_member, match_args,
+    Error, Signature, Span, from_type_member, match_args,
     namespace::{
         Member,
         traits::{Callable, Instance, Type},
@@ -15,6 +15,9 @@ impl Callable for Cuboid {
     fn full_name(&self) -> String {
         "Cuboid".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length", "Length"], "Part")]
+    }
     fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
         let (x, y, z) = match_args!(Length, Length, Length, args, span);
         Ok(Member::Instance(Box::new(Cuboid::from_dim(x, y, z))))
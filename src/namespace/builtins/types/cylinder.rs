@@ -1,7 +1,7 @@
 use anvil::{Cylinder, Length};
 
 use crate::{
-    Error, Span, from_type_member, match_args,
+    Error, Signature, Span, from_type_member, match_args,
     namespace::{
         Member,
         traits::{Callable, Instance, Type},
@@ -15,6 +15,9 @@ impl Callable for Cylinder {
     fn full_name(&self) -> String {
         "Cylinder".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length"], "Part")]
+    }
     fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
         let (radius, height) = match_args!(Length, Length, args, span);
         Ok(Member::Instance(Box::new(Cylinder::from_radius(
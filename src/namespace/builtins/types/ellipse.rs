@@ -0,0 +1,122 @@
+use std::f64::consts::TAU;
+
+use anvil::{Length, Path, Point, Sketch, point};
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+/// The number of arcs used to approximate the outline of an ellipse.
+const ELLIPSE_SEGMENTS: usize = 32;
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct EllipseType;
+impl Type for EllipseType {}
+from_type_member!(EllipseType);
+
+impl Callable for EllipseType {
+    fn full_name(&self) -> String {
+        "Ellipse".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length"], "Sketch")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (a, b) = match_args!(Length, Length, args, span);
+        if a.m() <= 0. || b.m() <= 0. {
+            return Err(Error::InvalidArgument(
+                "the semi-axes of an ellipse have to be larger than zero".into(),
+                span,
+            ));
+        }
+        Ok(Member::Instance(Box::new(ellipse(a, b))))
+    }
+}
+
+impl Instance for EllipseType {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+/// Approximate an ellipse with the semi-axes `a` (along x) and `b` (along y) by circular arcs
+/// through points on its outline.
+///
+/// anvil has no elliptic edges, so the result is not an exact ellipse. Every arc starts and ends
+/// on the true outline and passes through a third point on it.
+fn ellipse(a: Length, b: Length) -> Sketch {
+    let points = outline(a, b);
+    let mut path = Path::at(points[0]);
+    for i in 0..ELLIPSE_SEGMENTS {
+        path = path.arc_points(points[2 * i + 1], points[(2 * i + 2) % points.len()]);
+    }
+    path.close()
+}
+
+/// Return the points on the outline of an ellipse through which the approximating arcs pass.
+fn outline(a: Length, b: Length) -> Vec<Point<2>> {
+    (0..2 * ELLIPSE_SEGMENTS)
+        .map(|i| {
+            let angle = TAU * i as f64 / (2 * ELLIPSE_SEGMENTS) as f64;
+            point!(a * angle.cos(), b * angle.sin())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use anvil::IntoLength;
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Ellipse(3m, 2m)"),
+            Ok(ellipse(3.m(), 2.m()).into())
+        )
+    }
+
+    #[test]
+    fn outline_lies_on_ellipse() {
+        for point in outline(3.m(), 2.m()) {
+            let (x, y) = (point.x().m() / 3., point.y().m() / 2.);
+            assert!((x * x + y * y - 1.).abs() < 1e-9)
+        }
+    }
+
+    #[test]
+    fn outline_encloses_ellipse_area() {
+        let points = outline(3.m(), 2.m());
+        let shoelace: f64 = (0..points.len())
+            .map(|i| {
+                let (p, q) = (points[i], points[(i + 1) % points.len()]);
+                p.x().m() * q.y().m() - q.x().m() * p.y().m()
+            })
+            .sum();
+        let area = PI * 3. * 2.;
+        assert!((shoelace / 2. - area).abs() / area < 0.005)
+    }
+
+    #[test]
+    fn zero_semi_axis() {
+        let input = "Ellipse(3m, 0m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the semi-axes of an ellipse have to be larger than zero".into(),
+                Span::from((0, 15, input))
+            ))
+        )
+    }
+}
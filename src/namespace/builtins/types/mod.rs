@@ -1,9 +1,20 @@
 pub mod axis;
 pub mod circle;
+pub mod cone;
 pub mod cube;
 pub mod cuboid;
 pub mod cylinder;
+pub mod ellipse;
 pub mod path;
 pub mod plane;
+pub mod point;
+pub mod polygon;
+pub mod prism;
 pub mod rectangle;
+pub mod regular_polygon;
+pub mod rounded_rectangle;
+pub mod slot;
 pub mod sphere;
+pub mod torus;
+pub mod vec3;
+pub mod wedge;
@@ -1,9 +1,10 @@
-use anvil::{Length, Path, point};
+use anvil::Path;
 
 use crate::{
-    Error, Span, from_type_member, match_args,
+    Error, Span, from_type_member,
     namespace::{
         Member,
+        builtins::geometry::point_2d_from_args,
         traits::{Callable, Instance, Type},
     },
 };
@@ -18,8 +19,8 @@ impl Callable for PathType {
         "Path".into()
     }
     fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
-        let (x, y) = match_args!(Length, Length, args, span);
-        Ok(Member::Instance(Box::new(Path::at(point!(x, y)))))
+        let point = point_2d_from_args(args, &span)?;
+        Ok(Member::Instance(Box::new(Path::at(point))))
     }
 }
 
@@ -44,4 +45,12 @@ mod tests {
             Ok(Path::at(point!(1.m(), 2.m())).into())
         )
     }
+
+    #[test]
+    fn construct_from_point() {
+        assert_eq!(
+            eval_str("Path(Point(1m, 2m))"),
+            Ok(Path::at(point!(1.m(), 2.m())).into())
+        )
+    }
 }
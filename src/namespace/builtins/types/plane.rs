@@ -1,8 +1,11 @@
 use anvil::Plane;
 
 use crate::{
-    from_type_member, match_args,
-    namespace::traits::{Callable, Instance, Type},
+    Error, Member, Signature, Span, from_type_member, match_args,
+    namespace::{
+        builtins::geometry::{perpendicular, plane_from_m, point_and_direction_from_args},
+        traits::{Callable, Instance, Type},
+    },
 };
 
 #[derive(Clone, Debug, PartialEq)]
@@ -14,6 +17,10 @@ impl Callable for PlaneType {
     fn full_name(&self) -> String {
         "Plane".into()
     }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (origin, normal) = point_and_direction_from_args(args, &span)?;
+        Ok(plane_from_m(origin, perpendicular(normal), normal, &span)?.into())
+    }
 }
 
 impl Instance for PlaneType {
@@ -34,6 +41,9 @@ impl Callable for XY {
     fn full_name(&self) -> String {
         "Plane.XY".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Plane")]
+    }
     fn call(
         &self,
         args: &[crate::Member],
@@ -50,6 +60,9 @@ impl Callable for XZ {
     fn full_name(&self) -> String {
         "Plane.XZ".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Plane")]
+    }
     fn call(
         &self,
         args: &[crate::Member],
@@ -66,6 +79,9 @@ impl Callable for YZ {
     fn full_name(&self) -> String {
         "Plane.YZ".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&[], "Plane")]
+    }
     fn call(
         &self,
         args: &[crate::Member],
@@ -78,10 +94,46 @@ impl Callable for YZ {
 
 #[cfg(test)]
 mod tests {
-    use anvil::Plane;
+    use anvil::{Dir, IntoLength, Plane, point};
 
     use crate::eval_str;
 
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Plane(0m, 0m, 1m, 0, 0, 1)"),
+            Ok(Plane::new(
+                point!(0.m(), 0.m(), 1.m()),
+                Dir::try_from([1., 0., 0.]).unwrap(),
+                Dir::try_from([0., 0., 1.]).unwrap()
+            )
+            .unwrap()
+            .into())
+        )
+    }
+
+    #[test]
+    fn construct_from_point() {
+        assert_eq!(
+            eval_str("Plane(Point(0m, 0m, 1m), 0, 0, 5)"),
+            eval_str("Plane(0m, 0m, 1m, 0, 0, 1)")
+        )
+    }
+
+    #[test]
+    fn construct_with_normal_along_x() {
+        assert_eq!(
+            eval_str("Plane(0m, 0m, 0m, 1, 0, 0)"),
+            Ok(Plane::new(
+                point!(0.m(), 0.m(), 0.m()),
+                Dir::try_from([0., 1., 0.]).unwrap(),
+                Dir::try_from([1., 0., 0.]).unwrap()
+            )
+            .unwrap()
+            .into())
+        )
+    }
+
     #[test]
     fn xy_constructor() {
         assert_eq!(eval_str("Plane.XY()"), Ok(Plane::xy().into()))
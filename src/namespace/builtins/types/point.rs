@@ -0,0 +1,63 @@
+use anvil::{Length, point};
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PointType;
+impl Type for PointType {}
+from_type_member!(PointType);
+
+impl Callable for PointType {
+    fn full_name(&self) -> String {
+        "Point".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![
+            Signature::new(&["Length", "Length"], "Point"),
+            Signature::new(&["Length", "Length", "Length"], "Point"),
+        ]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        if args.len() == 2 {
+            let (x, y) = match_args!(Length, Length, args, span);
+            Ok(point!(x, y).into())
+        } else {
+            let (x, y, z) = match_args!(Length, Length, Length, args, span);
+            Ok(point!(x, y, z).into())
+        }
+    }
+}
+
+impl Instance for PointType {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::eval_str;
+    use anvil::{IntoLength, point};
+
+    #[test]
+    fn construct_2d() {
+        assert_eq!(eval_str("Point(1m, 2m)"), Ok(point!(1.m(), 2.m()).into()))
+    }
+
+    #[test]
+    fn construct_3d() {
+        assert_eq!(
+            eval_str("Point(1m, 2m, 3m)"),
+            Ok(point!(1.m(), 2.m(), 3.m()).into())
+        )
+    }
+}
@@ -0,0 +1,124 @@
+use anvil::{Length, Path, Point, point};
+
+use crate::{
+    Error, Span, from_type_member,
+    namespace::{
+        Member,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PolygonType;
+impl Type for PolygonType {}
+from_type_member!(PolygonType);
+
+impl Callable for PolygonType {
+    fn full_name(&self) -> String {
+        "Polygon".into()
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let points = points_from_args(args, &span)?;
+        if points.len() < 3 {
+            return Err(Error::InvalidArgument(
+                "a polygon needs at least 3 points".into(),
+                span,
+            ));
+        }
+
+        let mut path = Path::at(points[0]);
+        for point in &points[1..] {
+            path = path.line_to(*point);
+        }
+        Ok(Member::Instance(Box::new(path.close())))
+    }
+}
+
+impl Instance for PolygonType {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+/// Interpret the arguments either as 2D points or as alternating x and y coordinates.
+fn points_from_args(args: &[Member], span: &Span) -> Result<Vec<Point<2>>, Error> {
+    let points: Option<Vec<Point<2>>> = args
+        .iter()
+        .map(|arg| arg.downcast_ref::<Point<2>>().copied())
+        .collect();
+    if let Some(points) = points {
+        return Ok(points);
+    }
+
+    let lengths: Option<Vec<Length>> = args
+        .iter()
+        .map(|arg| arg.downcast_ref::<Length>().copied())
+        .collect();
+
+    match lengths {
+        Some(lengths) if lengths.len() % 2 == 0 => {
+            Ok(lengths.chunks(2).map(|xy| point!(xy[0], xy[1])).collect())
+        }
+        _ => Err(Error::Arguments {
+            should: vec!["Length".into(); args.len() + args.len() % 2],
+            is: args.iter().map(|arg| arg.type_name()).collect(),
+            span: span.clone(),
+        }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::{IntoLength, Path, point};
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Polygon(0m, 0m, 2m, 0m, 0m, 1m)"),
+            Ok(Path::at(point!(0.m(), 0.m()))
+                .line_to(point!(2.m(), 0.m()))
+                .line_to(point!(0.m(), 1.m()))
+                .close()
+                .into())
+        )
+    }
+
+    #[test]
+    fn construct_from_points() {
+        assert_eq!(
+            eval_str("Polygon(Point(0m, 0m), Point(2m, 0m), Point(0m, 1m))"),
+            eval_str("Polygon(0m, 0m, 2m, 0m, 0m, 1m)")
+        )
+    }
+
+    #[test]
+    fn odd_number_of_coordinates() {
+        let input = "Polygon(0m, 0m, 2m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::Arguments {
+                should: vec!["Length".into(); 4],
+                is: vec!["Length".into(); 3],
+                span: Span::from((0, 19, input))
+            })
+        )
+    }
+
+    #[test]
+    fn too_few_points() {
+        let input = "Polygon(0m, 0m, 2m, 0m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "a polygon needs at least 3 points".into(),
+                Span::from((0, 23, input))
+            ))
+        )
+    }
+}
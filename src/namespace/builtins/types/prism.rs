@@ -0,0 +1,79 @@
+use anvil::{Length, Plane};
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        builtins::types::regular_polygon::regular_polygon,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct PrismType;
+impl Type for PrismType {}
+from_type_member!(PrismType);
+
+impl Callable for PrismType {
+    fn full_name(&self) -> String {
+        "Prism".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Number", "Length", "Length"], "Part")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (sides, radius, height) = match_args!(f64, Length, Length, args, span);
+        if radius.m() <= 0. || height.m() <= 0. {
+            return Err(Error::InvalidArgument(
+                "the radius and height of a prism have to be larger than zero".into(),
+                span,
+            ));
+        }
+        let prism = Error::from_anvil(
+            regular_polygon(sides, radius, span.clone())?.extrude(Plane::xy(), height),
+            Some(span),
+        )?;
+        Ok(Member::Instance(Box::new(prism)))
+    }
+}
+
+impl Instance for PrismType {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::IntoLength;
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Prism(6, 2m, 5m)"),
+            Ok(regular_polygon(6., 2.m(), Span::from((0, 0)))
+                .unwrap()
+                .extrude(Plane::xy(), 5.m())
+                .unwrap()
+                .into())
+        )
+    }
+
+    #[test]
+    fn construct_with_zero_height() {
+        let input = "Prism(6, 2m, 0m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the radius and height of a prism have to be larger than zero".into(),
+                Span::from((0, 16, input))
+            ))
+        )
+    }
+}
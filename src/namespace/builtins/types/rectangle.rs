@@ -1,7 +1,7 @@
 use anvil::{Length, Rectangle};
 
 use crate::{
-    Error, Span, from_type_member, match_args,
+    Error, Signature, Span, from_type_member, match_args,
     namespace::{
         Member,
         traits::{Callable, Instance, Type},
@@ -15,6 +15,9 @@ impl Callable for Rectangle {
     fn full_name(&self) -> String {
         "Rectangle".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length"], "Sketch")]
+    }
     fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
         let (x, y) = match_args!(Length, Length, args, span);
         Ok(Member::Instance(Box::new(Rectangle::from_dim(x, y))))
@@ -0,0 +1,114 @@
+use std::f64::consts::TAU;
+
+use anvil::{Length, Path, Sketch, point};
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RegularPolygonType;
+impl Type for RegularPolygonType {}
+from_type_member!(RegularPolygonType);
+
+impl Callable for RegularPolygonType {
+    fn full_name(&self) -> String {
+        "RegularPolygon".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Number", "Length"], "Sketch")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (sides, radius) = match_args!(f64, Length, args, span);
+        Ok(Member::Instance(Box::new(regular_polygon(
+            sides, radius, span,
+        )?)))
+    }
+}
+
+impl Instance for RegularPolygonType {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+/// Return a regular polygon with its first vertex on the x-axis at the given circumradius.
+pub fn regular_polygon(sides: f64, radius: Length, span: Span) -> Result<Sketch, Error> {
+    if sides.fract() != 0. {
+        return Err(Error::InvalidArgument(
+            "the number of sides of a regular polygon has to be a whole number".into(),
+            span,
+        ));
+    }
+    if sides < 3. {
+        return Err(Error::InvalidArgument(
+            "a regular polygon needs at least 3 sides".into(),
+            span,
+        ));
+    }
+
+    let sides = sides as usize;
+    let vertex = |i: usize| {
+        let angle = TAU * i as f64 / sides as f64;
+        point!(radius * angle.cos(), radius * angle.sin())
+    };
+    let mut path = Path::at(vertex(0));
+    for i in 1..sides {
+        path = path.line_to(vertex(i));
+    }
+    Ok(path.close())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use anvil::{IntoLength, Path, point};
+
+    use crate::{Error, Span, eval_str};
+
+    #[test]
+    fn construct() {
+        let vertex = |angle: f64| point!(1.m() * angle.cos(), 1.m() * angle.sin());
+        assert_eq!(
+            eval_str("RegularPolygon(4, 1m)"),
+            Ok(Path::at(vertex(0.))
+                .line_to(vertex(TAU / 4.))
+                .line_to(vertex(TAU / 2.))
+                .line_to(vertex(3. * TAU / 4.))
+                .close()
+                .into())
+        )
+    }
+
+    #[test]
+    fn fractional_sides() {
+        let input = "RegularPolygon(3.5, 1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the number of sides of a regular polygon has to be a whole number".into(),
+                Span::from((0, 23, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn too_few_sides() {
+        let input = "RegularPolygon(2, 1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "a regular polygon needs at least 3 sides".into(),
+                Span::from((0, 21, input))
+            ))
+        )
+    }
+}
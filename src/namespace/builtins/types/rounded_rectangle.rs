@@ -0,0 +1,109 @@
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use anvil::{Length, Path, Rectangle, point};
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct RoundedRectangleType;
+impl Type for RoundedRectangleType {}
+from_type_member!(RoundedRectangleType);
+
+impl Callable for RoundedRectangleType {
+    fn full_name(&self) -> String {
+        "RoundedRectangle".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length", "Length"], "Sketch")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (width, height, radius) = match_args!(Length, Length, Length, args, span);
+        if radius.m() == 0. {
+            return Ok(Member::Instance(Box::new(Rectangle::from_dim(
+                width, height,
+            ))));
+        }
+        if radius.m() < 0. || 2. * radius.m() >= width.m().min(height.m()) {
+            return Err(Error::InvalidArgument(
+                "the corner radius of a rounded rectangle has to be smaller than half its width and height".into(),
+                span,
+            ));
+        }
+
+        let (x, y, r) = (width.m() / 2., height.m() / 2., radius.m());
+        let (x_inner, y_inner, d) = (x - r, y - r, r * FRAC_1_SQRT_2);
+        let p = |x: f64, y: f64| point!(Length::from_m(x), Length::from_m(y));
+        let sketch = Path::at(p(x_inner, -y))
+            .arc_points(p(x_inner + d, -y_inner - d), p(x, -y_inner))
+            .line_to(p(x, y_inner))
+            .arc_points(p(x_inner + d, y_inner + d), p(x_inner, y))
+            .line_to(p(-x_inner, y))
+            .arc_points(p(-x_inner - d, y_inner + d), p(-x, y_inner))
+            .line_to(p(-x, -y_inner))
+            .arc_points(p(-x_inner - d, -y_inner - d), p(-x_inner, -y))
+            .close();
+        Ok(Member::Instance(Box::new(sketch)))
+    }
+}
+
+impl Instance for RoundedRectangleType {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::{IntoLength, Rectangle};
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn construct_without_radius() {
+        assert_eq!(
+            eval_str("RoundedRectangle(5m, 6m, 0m)"),
+            Ok(Rectangle::from_dim(5.m(), 6.m()).into())
+        )
+    }
+
+    #[test]
+    fn construct_with_radius() {
+        let d = FRAC_1_SQRT_2;
+        let p = |x: f64, y: f64| point!(x.m(), y.m());
+        assert_eq!(
+            eval_str("RoundedRectangle(4m, 6m, 1m)"),
+            Ok(Path::at(p(1., -3.))
+                .arc_points(p(1. + d, -2. - d), p(2., -2.))
+                .line_to(p(2., 2.))
+                .arc_points(p(1. + d, 2. + d), p(1., 3.))
+                .line_to(p(-1., 3.))
+                .arc_points(p(-1. - d, 2. + d), p(-2., 2.))
+                .line_to(p(-2., -2.))
+                .arc_points(p(-1. - d, -2. - d), p(-1., -3.))
+                .close()
+                .into())
+        )
+    }
+
+    #[test]
+    fn radius_too_large() {
+        let input = "RoundedRectangle(2m, 6m, 1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the corner radius of a rounded rectangle has to be smaller than half its width and height".into(),
+                Span::from((0, 28, input))
+            ))
+        )
+    }
+}
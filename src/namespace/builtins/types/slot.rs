@@ -0,0 +1,79 @@
+use anvil::{Circle, Length, Rectangle, point};
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct SlotType;
+impl Type for SlotType {}
+from_type_member!(SlotType);
+
+impl Callable for SlotType {
+    fn full_name(&self) -> String {
+        "Slot".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length"], "Sketch")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (length, width) = match_args!(Length, Length, args, span);
+        if length.m() <= width.m() {
+            return Err(Error::InvalidArgument(
+                "the length of a slot has to be larger than its width".into(),
+                span,
+            ));
+        }
+
+        let offset = (length - width) / 2.;
+        let end_cap = Circle::from_radius(width / 2.);
+        Ok(Member::Instance(Box::new(
+            Rectangle::from_dim(length - width, width)
+                .add(&end_cap.move_to(point!(offset, Length::from_m(0.))))
+                .add(&end_cap.move_to(point!(offset * -1., Length::from_m(0.)))),
+        )))
+    }
+}
+
+impl Instance for SlotType {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::{Circle, IntoLength, Rectangle, point};
+
+    use crate::{Error, Span, eval_str};
+
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Slot(5m, 2m)"),
+            Ok(Rectangle::from_dim(3.m(), 2.m())
+                .add(&Circle::from_radius(1.m()).move_to(point!(1.5.m(), 0.m())))
+                .add(&Circle::from_radius(1.m()).move_to(point!((-1.5).m(), 0.m())))
+                .into())
+        )
+    }
+
+    #[test]
+    fn length_equal_to_width() {
+        let input = "Slot(2m, 2m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the length of a slot has to be larger than its width".into(),
+                Span::from((0, 12, input))
+            ))
+        )
+    }
+}
@@ -1,7 +1,7 @@
 use anvil::{Length, Sphere};
 
 use crate::{
-    Error, Span, from_type_member, match_args,
+    Error, Signature, Span, from_type_member, match_args,
     namespace::{
         Member,
         traits::{Callable, Instance, Type},
@@ -15,6 +15,9 @@ impl Callable for Sphere {
     fn full_name(&self) -> String {
         "Sphere".into()
     }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length"], "Part")]
+    }
     fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
         let radius = match_args!(Length, args, span);
         Ok(Member::Instance(Box::new(Sphere::from_radius(*radius))))
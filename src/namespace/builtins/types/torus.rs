@@ -0,0 +1,89 @@
+use anvil::{Length, Torus};
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+impl Type for Torus {}
+from_type_member!(Torus);
+
+impl Callable for Torus {
+    fn full_name(&self) -> String {
+        "Torus".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length"], "Part")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (major_radius, minor_radius) = match_args!(Length, Length, args, span);
+        if major_radius.m() <= 0. || minor_radius.m() <= 0. {
+            return Err(Error::InvalidArgument(
+                "the radii of a torus have to be larger than zero".into(),
+                span,
+            ));
+        }
+        if minor_radius.m() >= major_radius.m() {
+            return Err(Error::InvalidArgument(
+                "the tube radius of a torus has to be smaller than its ring radius".into(),
+                span,
+            ));
+        }
+        Ok(Member::Instance(Box::new(Torus::from_radii(
+            major_radius,
+            minor_radius,
+        ))))
+    }
+}
+
+impl Instance for Torus {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::IntoLength;
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Torus(5m, 1m)"),
+            Ok(Torus::from_radii(5.m(), 1.m()).into())
+        )
+    }
+
+    #[test]
+    fn construct_with_negative_radius() {
+        let input = "Torus(5m, -1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the radii of a torus have to be larger than zero".into(),
+                Span::from((0, 14, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn construct_with_tube_larger_than_ring() {
+        let input = "Torus(1m, 2m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the tube radius of a torus has to be smaller than its ring radius".into(),
+                Span::from((0, 13, input))
+            ))
+        )
+    }
+}
@@ -0,0 +1,53 @@
+use anvil::Length;
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        builtins::Vec3,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Vec3Type;
+impl Type for Vec3Type {}
+from_type_member!(Vec3Type);
+
+impl Callable for Vec3Type {
+    fn full_name(&self) -> String {
+        "Vec3".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length", "Length"], "Vec3")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (x, y, z) = match_args!(Length, Length, Length, args, span);
+        Ok(Vec3(x, y, z).into())
+    }
+}
+
+impl Instance for Vec3Type {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::IntoLength;
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Vec3(1m, 2m, 3m)"),
+            Ok(Vec3(1.m(), 2.m(), 3.m()).into())
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use anvil::{Length, Part, Path, Plane, point};
+
+use crate::{
+    Error, Signature, Span, from_type_member, match_args,
+    namespace::{
+        Member,
+        traits::{Callable, Instance, Type},
+    },
+};
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct WedgeType;
+impl Type for WedgeType {}
+from_type_member!(WedgeType);
+
+impl Callable for WedgeType {
+    fn full_name(&self) -> String {
+        "Wedge".into()
+    }
+    fn signatures(&self) -> Vec<Signature> {
+        vec![Signature::new(&["Length", "Length", "Length"], "Part")]
+    }
+    fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
+        let (x, y, z) = match_args!(Length, Length, Length, args, span);
+        if x.m() <= 0. || y.m() <= 0. || z.m() <= 0. {
+            return Err(Error::InvalidArgument(
+                "the sizes of a wedge have to be larger than zero".into(),
+                span,
+            ));
+        }
+        let wedge = Error::from_anvil(wedge(x, y, z), Some(span))?;
+        Ok(Member::Instance(Box::new(wedge)))
+    }
+}
+
+impl Instance for WedgeType {
+    fn eq(&self, other: &dyn Instance) -> bool {
+        other.downcast_ref::<Self>().is_some()
+    }
+    fn type_name(&self) -> String {
+        "Type".into()
+    }
+}
+
+/// Return a right-angled wedge that slopes from height `z` at the origin down to zero at `x` and
+/// is `y` deep.
+pub fn wedge(x: Length, y: Length, z: Length) -> Result<Part, anvil::Error> {
+    let zero = Length::from_m(0.);
+    Path::at(point!(zero, zero))
+        .line_to(point!(x, zero))
+        .line_to(point!(zero, z))
+        .close()
+        .extrude(Plane::xz(), y)
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::IntoLength;
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn construct() {
+        assert_eq!(
+            eval_str("Wedge(5m, 6m, 7m)"),
+            Ok(wedge(5.m(), 6.m(), 7.m()).unwrap().into())
+        )
+    }
+
+    #[test]
+    fn construct_with_zero_size() {
+        let input = "Wedge(0m, 1m, 1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the sizes of a wedge have to be larger than zero".into(),
+                Span::from((0, 17, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn construct_with_negative_size() {
+        let input = "Wedge(1m, 1m, -1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "the sizes of a wedge have to be larger than zero".into(),
+                Span::from((0, 18, input))
+            ))
+        )
+    }
+}
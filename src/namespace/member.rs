@@ -1,7 +1,10 @@
-use anvil::{Angle, Length};
 use regex::Regex;
 
-use crate::{Error, Span, Type, namespace::traits::Instance};
+use crate::{
+    Callable, Error, Span, Type,
+    errors::suggest,
+    namespace::{traits::Instance, units::UnitTable},
+};
 
 #[derive(Clone, Debug, PartialEq)]
 pub enum Member {
@@ -9,21 +12,37 @@ pub enum Member {
     Type(Box<dyn Type>),
 }
 impl Member {
+    /// Parse a literal using the builtin units.
     pub fn from_str(literal: &str, span: Span) -> Result<Self, Error> {
-        let (number, unit) = match split_number_and_unit(literal) {
-            Some(v) => v,
-            None => todo!(),
+        Self::from_str_with_units(literal, &UnitTable::default(), span)
+    }
+    /// Parse a literal using the units of the given table.
+    pub fn from_str_with_units(
+        literal: &str,
+        units: &UnitTable,
+        span: Span,
+    ) -> Result<Self, Error> {
+        let Some((number, unit)) = split_number_and_unit(literal) else {
+            return Err(Error::InvalidLiteral(literal.into(), span));
         };
 
-        // TODO: Add remaining units
-        match unit {
-            "" => Ok(Member::Instance(Box::new(number))),
-            "m" => Ok(Member::Instance(Box::new(Length::from_m(number)))),
-            "cm" => Ok(Member::Instance(Box::new(Length::from_cm(number)))),
-            "mm" => Ok(Member::Instance(Box::new(Length::from_mm(number)))),
-            "deg" => Ok(Member::Instance(Box::new(Angle::from_deg(number)))),
-            "rad" => Ok(Member::Instance(Box::new(Angle::from_rad(number)))),
-            _ => Err(Error::UnknownUnit(unit.into(), span)),
+        if unit.is_empty() {
+            return Ok(Member::Instance(Box::new(number)));
+        }
+        match units.get(unit) {
+            Some(unit) => Ok(unit.member(number)),
+            None => Err(Error::UnknownUnit(
+                unit.into(),
+                suggest(unit, units.symbols()),
+                span,
+            )),
+        }
+    }
+    /// Return a reference to the inner instance if it is of type `T`.
+    pub fn downcast_ref<T: Instance>(&self) -> Option<&T> {
+        match self {
+            Member::Instance(inner) => inner.downcast_ref::<T>(),
+            Member::Type(_) => None,
         }
     }
     pub fn type_name(&self) -> String {
@@ -32,16 +51,35 @@ impl Member {
             Member::Type(_) => "Type".into(),
         }
     }
+    /// Return the text representing this member in format strings and printed output.
+    pub fn display(&self) -> String {
+        match self {
+            Member::Instance(inner) => inner.display(),
+            Member::Type(inner) => format!("<{}>", inner.full_name()),
+        }
+    }
     pub fn method(&self, name: String, args: &[Member], span: &Span) -> Result<Self, Error> {
+        self.callable_method(name, span)?.call(args, span.clone())
+    }
+    /// Return the short names of all methods of this member.
+    pub fn method_names(&self) -> Vec<String> {
+        let methods = match self {
+            Self::Instance(inner) => inner.methods(),
+            Self::Type(inner) => inner.methods(),
+        };
+        methods.iter().map(|method| method.short_name()).collect()
+    }
+    /// Return the method with the given name without calling it.
+    pub fn callable_method(&self, name: String, span: &Span) -> Result<Box<dyn Callable>, Error> {
         match self {
-            Self::Instance(inner) => inner.method(name, span)?.call(args, span.clone()),
-            Self::Type(inner) => inner.method(name, span)?.call(args, span.clone()),
+            Self::Instance(inner) => inner.method(name, span),
+            Self::Type(inner) => inner.method(name, span),
         }
     }
 }
 
 fn split_number_and_unit(literal: &str) -> Option<(f64, &str)> {
-    let re = Regex::new(r"^(-?[0-9]*\.?[0-9]+)([a-zA-Z]+)?$").ok()?;
+    let re = Regex::new(r"^(-?[0-9]*\.?[0-9]+(?:[eE][+-]?[0-9]+)?)([a-zA-Z]+)?$").ok()?;
     let caps = re.captures(literal)?;
 
     let number = caps.get(1)?.as_str().parse::<f64>().ok()?;
@@ -57,6 +95,18 @@ fn split_number_and_unit(literal: &str) -> Option<(f64, &str)> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn invalid_literal() {
+        let input = "x = 1.2.3";
+        assert_eq!(
+            crate::compile_input(input),
+            Err(Error::InvalidLiteral(
+                "1.2.3".into(),
+                Span::from((4, 9, input))
+            ))
+        )
+    }
+
     #[test]
     fn test_split_number_and_unit_int_mm() {
         assert_eq!(split_number_and_unit("5mm"), Some((5., "mm")))
@@ -67,6 +117,21 @@ mod tests {
         assert_eq!(split_number_and_unit("3.15mm"), Some((3.15, "mm")))
     }
 
+    #[test]
+    fn test_split_number_and_unit_scientific() {
+        assert_eq!(split_number_and_unit("1.5e-3m"), Some((1.5e-3, "m")))
+    }
+
+    #[test]
+    fn test_split_number_and_unit_scientific_no_unit() {
+        assert_eq!(split_number_and_unit("2E3"), Some((2000., "")))
+    }
+
+    #[test]
+    fn test_split_number_and_unit_unit_starting_with_e() {
+        assert_eq!(split_number_and_unit("5em"), Some((5., "em")))
+    }
+
     #[test]
     fn test_split_number_and_unit_int_no_unit() {
         assert_eq!(split_number_and_unit("3"), Some((3., "")))
@@ -2,7 +2,10 @@ pub mod builtins;
 mod member;
 #[allow(clippy::module_inception)]
 mod namespace;
+mod quantity;
 pub mod traits;
+mod units;
 
 pub use member::Member;
 pub use namespace::PartNamespace;
+pub use units::{DefaultUnits, Unit, UnitTable};
@@ -1,8 +1,25 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{
+    cell::RefCell,
+    collections::HashMap,
+    f64::consts::{PI, TAU},
+    fmt::Debug,
+    rc::Rc,
+};
 
-use anvil::{Circle, Cube, Cuboid, Cylinder, Part, Rectangle, Sphere};
+use anvil::{Circle, Cone, Cube, Cuboid, Cylinder, Part, Rectangle, Sphere, Torus};
 
-use crate::{AxisType, Callable, PathType, PlaneType, namespace::Member};
+use crate::{
+    AxisType, Callable, EllipseType, PathType, PlaneType, PointType, PolygonType, PrismType,
+    RegularPolygonType, RoundedRectangleType, SlotType, Span, Unit, UnitTable, Vec3Type, WedgeType,
+    errors::Warning,
+    namespace::{
+        DefaultUnits, Member,
+        builtins::{
+            math::{Abs, Atan2, Ceil, Clamp, Cos, Floor, Hypot, Max, Min, Round, Sin, Sqrt, Tan},
+            print::Print,
+        },
+    },
+};
 
 /// A hashmap containing all accessible variables and functions.
 ///
@@ -21,15 +38,44 @@ impl Default for Namespace {
     fn default() -> Self {
         Self(
             [
+                (Abs.full_name(), Abs.into()),
+                (Atan2.full_name(), Atan2.into()),
                 (AxisType.full_name(), AxisType.into()),
+                (Ceil.full_name(), Ceil.into()),
                 (Circle.full_name(), Circle.into()),
+                (Clamp.full_name(), Clamp.into()),
+                (Cone.full_name(), Cone.into()),
+                (Cos.full_name(), Cos.into()),
                 (Cube.full_name(), Cube.into()),
                 (Cuboid.full_name(), Cuboid.into()),
                 (Cylinder.full_name(), Cylinder.into()),
+                (EllipseType.full_name(), EllipseType.into()),
+                (Floor.full_name(), Floor.into()),
+                (Hypot.full_name(), Hypot.into()),
+                (Max.full_name(), Max.into()),
+                (Min.full_name(), Min.into()),
                 (PathType.full_name(), PathType.into()),
+                ("pi".into(), PI.into()),
                 (PlaneType.full_name(), PlaneType.into()),
+                (PointType.full_name(), PointType.into()),
+                (PolygonType.full_name(), PolygonType.into()),
+                (PrismType.full_name(), PrismType.into()),
                 (Rectangle.full_name(), Rectangle.into()),
+                (RegularPolygonType.full_name(), RegularPolygonType.into()),
+                (
+                    RoundedRectangleType.full_name(),
+                    RoundedRectangleType.into(),
+                ),
+                (Round.full_name(), Round.into()),
+                (Sin.full_name(), Sin.into()),
+                (SlotType.full_name(), SlotType.into()),
                 (Sphere.full_name(), Sphere.into()),
+                (Sqrt.full_name(), Sqrt.into()),
+                (Tan.full_name(), Tan.into()),
+                ("tau".into(), TAU.into()),
+                (Torus.full_name(), Torus.into()),
+                (Vec3Type.full_name(), Vec3Type.into()),
+                (WedgeType.full_name(), WedgeType.into()),
             ]
             .into(),
         )
@@ -44,6 +90,10 @@ impl Namespace {
     pub fn get(&self, k: &String) -> Option<&Member> {
         self.0.get(k)
     }
+    /// Returns the names of all members.
+    pub fn names(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
     /// Inserts a key-Member pair into the map.
     /// If the map did not have this key present, None is returned.
     pub fn insert(&mut self, k: String, v: Member) -> Option<Member> {
@@ -56,13 +106,35 @@ impl Namespace {
     }
 }
 
-/// A namespace with a reserved field for a part.
+/// A namespace with a reserved field for a part and the unit settings used when evaluating.
+///
+/// Clones of a PartNamespace (like the scopes of list comprehensions) share their warnings and
+/// printed output.
 #[derive(Clone, Debug, PartialEq)]
-pub struct PartNamespace(Namespace);
+pub struct PartNamespace {
+    members: Namespace,
+    units: UnitTable,
+    default_units: DefaultUnits,
+    warn_implicit_units: bool,
+    denied_lints: Vec<String>,
+    warnings: Rc<RefCell<Vec<Warning>>>,
+    output: Rc<RefCell<Vec<String>>>,
+}
 impl Default for PartNamespace {
     /// Return a PartNamespace with the builtin functions and an empty `part` shape included.
     fn default() -> Self {
-        Self(Namespace::new().insert_clone("part".into(), Part::empty().into()))
+        let output = Rc::new(RefCell::new(vec![]));
+        Self {
+            members: Namespace::new()
+                .insert_clone("part".into(), Part::empty().into())
+                .insert_clone("print".into(), Print::new(Rc::clone(&output)).into()),
+            units: UnitTable::default(),
+            default_units: DefaultUnits::default(),
+            warn_implicit_units: false,
+            denied_lints: vec![],
+            warnings: Rc::new(RefCell::new(vec![])),
+            output,
+        }
     }
 }
 impl PartNamespace {
@@ -70,14 +142,82 @@ impl PartNamespace {
     pub fn new() -> Self {
         Self::default()
     }
+    /// Return the units that can be used in literals.
+    pub fn units(&self) -> &UnitTable {
+        &self.units
+    }
+    /// Return a mutable reference to the units that can be used in literals.
+    pub fn units_mut(&mut self) -> &mut UnitTable {
+        &mut self.units
+    }
+    /// Return the units that bare numbers are converted to.
+    pub fn default_units(&self) -> &DefaultUnits {
+        &self.default_units
+    }
+    /// Convert bare numbers into the unit with the given symbol where its dimension is expected.
+    ///
+    /// Returns None if the unit is not in the unit table.
+    pub fn set_default_unit(&mut self, symbol: &str) -> Option<Unit> {
+        let unit = *self.units.get(symbol)?;
+        self.default_units.set(symbol, unit);
+        Some(unit)
+    }
+    /// Set whether a warning is emitted when a bare number is converted into a default unit.
+    pub fn warn_implicit_units(&mut self, warn: bool) {
+        self.warn_implicit_units = warn
+    }
+    /// Turn the warnings of a lint (like `unused`) into errors. `warnings` denies all lints.
+    pub fn deny(&mut self, lint: &str) {
+        self.denied_lints.push(lint.into())
+    }
+    /// Return true if warnings of the given lint are turned into errors.
+    pub fn is_denied(&self, lint: &str) -> bool {
+        self.denied_lints
+            .iter()
+            .any(|denied| denied == lint || denied == "warnings")
+    }
+    /// Record a warning for a bare number that was converted into a default unit.
+    pub fn implicit_unit_used(&self, unit: &str, span: Span) {
+        if self.warn_implicit_units {
+            self.warnings
+                .borrow_mut()
+                .push(Warning::ImplicitUnit(unit.into(), span));
+        }
+    }
+    /// Return the warnings that were emitted while evaluating in this namespace.
+    pub fn warnings(&self) -> Vec<Warning> {
+        self.warnings.borrow().clone()
+    }
+    /// Return the lines written by `print` while evaluating in this namespace.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{PartNamespace, compile_input_with_namespace};
+    ///
+    /// let mut namespace = PartNamespace::new();
+    /// let input = "
+    ///     part Box:
+    ///         width = 4mm
+    ///         print(f\"width={width}\", width * 2)
+    /// ";
+    /// compile_input_with_namespace(input, &mut namespace).unwrap();
+    /// assert_eq!(namespace.output(), vec!["width=4mm 8mm".to_string()])
+    /// ```
+    pub fn output(&self) -> Vec<String> {
+        self.output.borrow().clone()
+    }
     /// Returns a reference to the Member corresponding to the key.
     pub fn get(&self, k: &String) -> Option<&Member> {
-        self.0.get(k)
+        self.members.get(k)
+    }
+    /// Returns the names of all variables and functions.
+    pub fn names(&self) -> Vec<String> {
+        self.members.names()
     }
     /// Inserts a key-Member pair into the map.
     /// If the map did not have this key present, None is returned.
     pub fn insert(&mut self, k: String, v: Member) -> Option<Member> {
-        self.0.insert(k, v)
+        self.members.insert(k, v)
     }
     pub fn part(&self) -> Part {
         match self.get(&"part".into()) {
@@ -0,0 +1,712 @@
+use anvil::{Angle, Length};
+
+use crate::{Area, Error, Member, Signature, Span, Vec3, Volume};
+
+/// A scalar value in SI units together with the exponents of its dimensions.
+///
+/// This is what the arithmetic methods of `Number`, `Length`, `Area`, `Volume` and `Angle` work
+/// on, so that for example a `Length` multiplied by a `Length` results in an `Area`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Quantity {
+    value: f64,
+    length: i32,
+    angle: i32,
+}
+impl Quantity {
+    fn new(value: f64, length: i32, angle: i32) -> Self {
+        Self {
+            value,
+            length,
+            angle,
+        }
+    }
+
+    /// Return the quantity of a member if it is one of the scalar instances.
+    pub fn from_member(member: &Member) -> Option<Self> {
+        if let Some(number) = member.downcast_ref::<f64>() {
+            return Some((*number).into());
+        }
+        if let Some(length) = member.downcast_ref::<Length>() {
+            return Some((*length).into());
+        }
+        if let Some(area) = member.downcast_ref::<Area>() {
+            return Some((*area).into());
+        }
+        if let Some(volume) = member.downcast_ref::<Volume>() {
+            return Some((*volume).into());
+        }
+        if let Some(angle) = member.downcast_ref::<Angle>() {
+            return Some((*angle).into());
+        }
+        None
+    }
+
+    /// Return the member representing this quantity or None if no instance type has its dimensions.
+    pub fn to_member(self) -> Option<Member> {
+        match (self.length, self.angle) {
+            (0, 0) => Some(self.value.into()),
+            (1, 0) => Some(Length::from_m(self.value).into()),
+            (2, 0) => Some(Area::from_m2(self.value).into()),
+            (3, 0) => Some(Volume::from_m3(self.value).into()),
+            (0, 1) => Some(Angle::from_rad(self.value).into()),
+            _ => None,
+        }
+    }
+
+    /// Return the value of this quantity in SI units.
+    pub fn value(&self) -> f64 {
+        self.value
+    }
+
+    /// Return a quantity with the same dimensions but a different value.
+    pub fn with_value(self, value: f64) -> Self {
+        Self { value, ..self }
+    }
+
+    /// Check if another quantity has the same dimensions as this one.
+    pub fn has_dimension_of(&self, other: &Self) -> bool {
+        (self.length, self.angle) == (other.length, other.angle)
+    }
+
+    /// Return this quantity raised to a power or None if the resulting dimensions are fractional.
+    pub fn powf(self, exponent: f64) -> Option<Self> {
+        let length = self.length as f64 * exponent;
+        let angle = self.angle as f64 * exponent;
+        if length.fract() != 0. || angle.fract() != 0. {
+            return None;
+        }
+        Some(Self::new(
+            self.value.powf(exponent),
+            length as i32,
+            angle as i32,
+        ))
+    }
+
+    pub fn type_name(&self) -> String {
+        match self.to_member() {
+            Some(member) => member.type_name(),
+            None => format!("Quantity<length^{}, angle^{}>", self.length, self.angle),
+        }
+    }
+}
+impl From<f64> for Quantity {
+    fn from(value: f64) -> Self {
+        Self::new(value, 0, 0)
+    }
+}
+impl From<Length> for Quantity {
+    fn from(value: Length) -> Self {
+        Self::new(value.m(), 1, 0)
+    }
+}
+impl From<Area> for Quantity {
+    fn from(value: Area) -> Self {
+        Self::new(value.m2(), 2, 0)
+    }
+}
+impl From<Volume> for Quantity {
+    fn from(value: Volume) -> Self {
+        Self::new(value.m3(), 3, 0)
+    }
+}
+impl From<Angle> for Quantity {
+    fn from(value: Angle) -> Self {
+        Self::new(value.rad(), 0, 1)
+    }
+}
+
+/// Add a scalar member to a quantity, which is only possible if both have the same dimensions.
+pub fn add(left: Quantity, args: &[Member], span: Span) -> Result<Member, Error> {
+    let right = right_operand("add", left, args, &span)?;
+    if !left.has_dimension_of(&right) {
+        return Err(incompatible("add", left, right, span));
+    }
+    to_member(
+        "add",
+        left,
+        right,
+        Quantity::new(left.value + right.value, left.length, left.angle),
+        span,
+    )
+}
+
+/// Subtract a scalar member from a quantity, which is only possible if both have the same
+/// dimensions.
+pub fn subtract(left: Quantity, args: &[Member], span: Span) -> Result<Member, Error> {
+    let right = right_operand("subtract", left, args, &span)?;
+    if !left.has_dimension_of(&right) {
+        return Err(incompatible("subtract", left, right, span));
+    }
+    to_member(
+        "subtract",
+        left,
+        right,
+        Quantity::new(left.value - right.value, left.length, left.angle),
+        span,
+    )
+}
+
+/// Multiply a quantity by a scalar member, adding up the dimensions of both.
+///
+/// A number can also be multiplied by a vector, which scales the vector like `Vec3.multiply`.
+pub fn multiply(left: Quantity, args: &[Member], span: Span) -> Result<Member, Error> {
+    if let [arg] = args
+        && let Some(vector) = arg.downcast_ref::<Vec3>()
+        && left.has_dimension_of(&1.0.into())
+    {
+        return Ok(Vec3::from_m(vector.to_m().map(|component| component * left.value)).into());
+    }
+    let right = right_operand("multiply", left, args, &span)?;
+    to_member(
+        "multiply",
+        left,
+        right,
+        Quantity::new(
+            left.value * right.value,
+            left.length + right.length,
+            left.angle + right.angle,
+        ),
+        span,
+    )
+}
+
+/// Divide a quantity by a scalar member, subtracting the dimensions of the divisor.
+pub fn divide(left: Quantity, args: &[Member], span: Span) -> Result<Member, Error> {
+    let right = right_operand("divide", left, args, &span)?;
+    if right.value == 0. {
+        return Err(division_by_zero(span));
+    }
+    to_member(
+        "divide",
+        left,
+        right,
+        Quantity::new(
+            left.value / right.value,
+            left.length - right.length,
+            left.angle - right.angle,
+        ),
+        span,
+    )
+}
+
+/// Return the remainder of dividing a quantity by a scalar member of the same dimensions or by a
+/// number. The result has the sign of the divisor, so `-1mm % 5mm` is `4mm`.
+pub fn modulo(left: Quantity, args: &[Member], span: Span) -> Result<Member, Error> {
+    let right = right_operand("modulo", left, args, &span)?;
+    if !left.has_dimension_of(&right) && !right.has_dimension_of(&1.0.into()) {
+        return Err(incompatible("modulo", left, right, span));
+    }
+    if right.value == 0. {
+        return Err(division_by_zero(span));
+    }
+    let remainder = left.value % right.value;
+    let value = if remainder != 0. && remainder.signum() != right.value.signum() {
+        remainder + right.value
+    } else {
+        remainder
+    };
+    to_member("modulo", left, right, left.with_value(value), span)
+}
+
+/// Raise a quantity to the power of a number, multiplying its dimensions by the exponent.
+///
+/// Raising zero to a negative power is rejected like a division by zero.
+pub fn power(left: Quantity, args: &[Member], span: Span) -> Result<Member, Error> {
+    let exponent = match args {
+        [arg] => arg.downcast_ref::<f64>(),
+        _ => None,
+    };
+    let Some(exponent) = exponent else {
+        return Err(Error::Arguments {
+            should: vec!["Number".into()],
+            is: args.iter().map(|arg| arg.type_name()).collect(),
+            span,
+        });
+    };
+    if left.value == 0. && *exponent < 0. {
+        return Err(division_by_zero(span));
+    }
+    match left.powf(*exponent).and_then(Quantity::to_member) {
+        Some(member) => Ok(member),
+        None => Err(Error::InvalidArgument(
+            format!(
+                "{} to the power of {} has no supported unit",
+                left.type_name(),
+                exponent
+            ),
+            span,
+        )),
+    }
+}
+
+/// Return the signatures of an arithmetic operation on a quantity, with one signature for every
+/// scalar type of the right operand that the operation supports.
+pub fn signatures(
+    operation: fn(Quantity, &[Member], Span) -> Result<Member, Error>,
+    left: Quantity,
+) -> Vec<Signature> {
+    let operands: [Member; 6] = [
+        1.0.into(),
+        Length::from_m(1.).into(),
+        Area::from_m2(1.).into(),
+        Volume::from_m3(1.).into(),
+        Angle::from_rad(1.).into(),
+        Vec3::from_m([1., 1., 1.]).into(),
+    ];
+    operands
+        .iter()
+        .filter_map(|right| {
+            let result = operation(
+                left.with_value(1.),
+                std::slice::from_ref(right),
+                Span::from((0, 0)),
+            )
+            .ok()?;
+            Some(Signature::new(&[&right.type_name()], &result.type_name()))
+        })
+        .collect()
+}
+
+fn right_operand(
+    operation: &str,
+    left: Quantity,
+    args: &[Member],
+    span: &Span,
+) -> Result<Quantity, Error> {
+    match args {
+        [arg] => match Quantity::from_member(arg) {
+            Some(right) => Ok(right),
+            None => Err(Error::IncompatibleOperands {
+                operation: operation.into(),
+                left: left.type_name(),
+                right: arg.type_name(),
+                span: span.clone(),
+            }),
+        },
+        _ => Err(Error::Arguments {
+            should: vec![left.type_name()],
+            is: args.iter().map(|arg| arg.type_name()).collect(),
+            span: span.clone(),
+        }),
+    }
+}
+
+fn to_member(
+    operation: &str,
+    left: Quantity,
+    right: Quantity,
+    result: Quantity,
+    span: Span,
+) -> Result<Member, Error> {
+    match result.to_member() {
+        Some(member) => Ok(member),
+        None => Err(incompatible(operation, left, right, span)),
+    }
+}
+
+/// Return the error for dividing by zero or taking the remainder of a division by zero.
+pub(crate) fn division_by_zero(span: Span) -> Error {
+    Error::InvalidArgument("division by zero".into(), span)
+}
+
+fn incompatible(operation: &str, left: Quantity, right: Quantity, span: Span) -> Error {
+    Error::IncompatibleOperands {
+        operation: operation.into(),
+        left: left.type_name(),
+        right: right.type_name(),
+        span,
+    }
+}
+
+/// Implement the arithmetic methods of a scalar instance type by delegating to the functions of
+/// this module.
+///
+/// This defines the method callables and a function `quantity_methods` in the calling module,
+/// which returns them for a value of the type. The type has to convert into a `Quantity`.
+#[macro_export]
+macro_rules! quantity_methods {
+    ($type:ty, $name:literal) => {
+        fn quantity_methods(value: $type) -> Vec<Box<dyn $crate::Callable>> {
+            let value = std::sync::Arc::new(value);
+            vec![
+                Box::new(Add(std::sync::Arc::clone(&value))),
+                Box::new(Divide(std::sync::Arc::clone(&value))),
+                Box::new(Modulo(std::sync::Arc::clone(&value))),
+                Box::new(Multiply(std::sync::Arc::clone(&value))),
+                Box::new(Power(std::sync::Arc::clone(&value))),
+                Box::new(Subtract(value)),
+            ]
+        }
+
+        $crate::quantity_methods!(@method Add, add, $type, $name);
+        $crate::quantity_methods!(@method Divide, divide, $type, $name);
+        $crate::quantity_methods!(@method Modulo, modulo, $type, $name);
+        $crate::quantity_methods!(@method Multiply, multiply, $type, $name);
+        $crate::quantity_methods!(@method Subtract, subtract, $type, $name);
+
+        #[derive(Clone, Debug, PartialEq)]
+        struct Power(std::sync::Arc<$type>);
+        impl $crate::Callable for Power {
+            fn full_name(&self) -> String {
+                concat!($name, ".power").into()
+            }
+            fn call(
+                &self,
+                args: &[$crate::Member],
+                span: $crate::Span,
+            ) -> Result<$crate::Member, $crate::Error> {
+                $crate::namespace::quantity::power((*self.0).into(), args, span)
+            }
+        }
+    };
+    (@method $struct:ident, $operation:ident, $type:ty, $name:literal) => {
+        #[derive(Clone, Debug, PartialEq)]
+        struct $struct(std::sync::Arc<$type>);
+        impl $crate::Callable for $struct {
+            fn full_name(&self) -> String {
+                concat!($name, ".", stringify!($operation)).into()
+            }
+            fn signatures(&self) -> Vec<$crate::Signature> {
+                $crate::namespace::quantity::signatures(
+                    $crate::namespace::quantity::$operation,
+                    (*self.0).into(),
+                )
+            }
+            fn call(
+                &self,
+                args: &[$crate::Member],
+                span: $crate::Span,
+            ) -> Result<$crate::Member, $crate::Error> {
+                $crate::namespace::quantity::$operation((*self.0).into(), args, span)
+            }
+        }
+    };
+}
+
+/// Define a scalar instance type for a power of a length, like `Area` for square meters.
+///
+/// The type stores its value in SI units and gets constructors and getters for meters,
+/// centimeters and millimeters raised to the power, as well as the methods of
+/// `quantity_methods!`. The type has to convert into a `Quantity`.
+#[macro_export]
+macro_rules! length_power {
+    (
+        $(#[$meta:meta])*
+        $type:ident, $name:literal, $power:literal, $adjective:literal,
+        ($from_m:ident, $from_cm:ident, $from_mm:ident),
+        ($m:ident, $cm:ident, $mm:ident)
+    ) => {
+        $(#[$meta])*
+        #[derive(Clone, Copy, Debug, Default, PartialEq, PartialOrd)]
+        pub struct $type(f64);
+        impl $type {
+            #[doc = concat!("Construct a `", $name, "` from a value in ", $adjective, " meters.")]
+            pub fn $from_m(value: f64) -> Self {
+                Self(value)
+            }
+            #[doc = concat!("Construct a `", $name, "` from a value in ", $adjective, " centimeters.")]
+            pub fn $from_cm(value: f64) -> Self {
+                Self(value / 100f64.powi($power))
+            }
+            #[doc = concat!("Construct a `", $name, "` from a value in ", $adjective, " millimeters.")]
+            pub fn $from_mm(value: f64) -> Self {
+                Self(value / 1000f64.powi($power))
+            }
+            #[doc = concat!("Return the value of this `", $name, "` in ", $adjective, " meters.")]
+            pub fn $m(&self) -> f64 {
+                self.0
+            }
+            #[doc = concat!("Return the value of this `", $name, "` in ", $adjective, " centimeters.")]
+            pub fn $cm(&self) -> f64 {
+                self.0 * 100f64.powi($power)
+            }
+            #[doc = concat!("Return the value of this `", $name, "` in ", $adjective, " millimeters.")]
+            pub fn $mm(&self) -> f64 {
+                self.0 * 1000f64.powi($power)
+            }
+        }
+        impl std::ops::Add for $type {
+            type Output = Self;
+            fn add(self, other: Self) -> Self {
+                Self(self.0 + other.0)
+            }
+        }
+        impl std::ops::Sub for $type {
+            type Output = Self;
+            fn sub(self, other: Self) -> Self {
+                Self(self.0 - other.0)
+            }
+        }
+        impl std::ops::Mul<f64> for $type {
+            type Output = Self;
+            fn mul(self, other: f64) -> Self {
+                Self(self.0 * other)
+            }
+        }
+        impl std::ops::Div<f64> for $type {
+            type Output = Self;
+            fn div(self, other: f64) -> Self {
+                Self(self.0 / other)
+            }
+        }
+
+        impl $crate::Instance for $type {
+            fn methods(&self) -> Vec<Box<dyn $crate::Callable>> {
+                quantity_methods(*self)
+            }
+            fn eq(&self, other: &dyn $crate::Instance) -> bool {
+                match other.downcast_ref::<$type>() {
+                    Some(o) => self == o,
+                    None => false,
+                }
+            }
+            fn type_name(&self) -> String {
+                $name.into()
+            }
+            fn display(&self) -> String {
+                format!(concat!("{}mm^", $power), self.$mm())
+            }
+        }
+        $crate::from_instance_member!($type);
+        $crate::quantity_methods!($type, $name);
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::{IntoAngle, IntoLength};
+
+    use super::*;
+    use crate::eval_str;
+
+    #[test]
+    fn length_times_length() {
+        assert_eq!(eval_str("2m * 3m"), Ok(Area::from_m2(6.).into()))
+    }
+
+    #[test]
+    fn area_times_length() {
+        assert_eq!(eval_str("2m * 3m * 4m"), Ok(Volume::from_m3(24.).into()))
+    }
+
+    #[test]
+    fn length_times_area() {
+        assert_eq!(eval_str("4m * (2m * 3m)"), Ok(Volume::from_m3(24.).into()))
+    }
+
+    #[test]
+    fn volume_divided_by_area() {
+        assert_eq!(eval_str("(2m * 3m * 4m) / (2m * 3m)"), Ok(4.m().into()))
+    }
+
+    #[test]
+    fn length_divided_by_length() {
+        assert_eq!(eval_str("6m / 2m"), Ok(3.0.into()))
+    }
+
+    #[test]
+    fn angle_divided_by_angle() {
+        assert_eq!(eval_str("90deg / 45deg"), Ok(2.0.into()))
+    }
+
+    #[test]
+    fn number_times_length() {
+        assert_eq!(eval_str("2 * 3m"), Ok(6.m().into()))
+    }
+
+    #[test]
+    fn number_times_vector() {
+        assert_eq!(
+            eval_str("2 * Vec3(1m, 2m, 3m)"),
+            Ok(Vec3(2.m(), 4.m(), 6.m()).into())
+        );
+        assert_eq!(
+            eval_str("2 * Vec3(1m, 2m, 3m)"),
+            eval_str("Vec3(1m, 2m, 3m) * 2")
+        )
+    }
+
+    #[test]
+    fn length_times_vector() {
+        let input = "2m * Vec3(1m, 2m, 3m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::IncompatibleOperands {
+                operation: "multiply".into(),
+                left: "Length".into(),
+                right: "Vec3".into(),
+                span: Span::from((0, 21, input))
+            })
+        )
+    }
+
+    #[test]
+    fn number_times_angle() {
+        assert_eq!(eval_str("2 * 0.5rad"), Ok(1.rad().into()))
+    }
+
+    #[test]
+    fn length_modulo_length() {
+        assert_eq!(eval_str("7m % 2m"), Ok(1.m().into()))
+    }
+
+    #[test]
+    fn negative_modulo() {
+        assert_eq!(eval_str("-1m % 5m"), Ok(4.m().into()))
+    }
+
+    #[test]
+    fn length_modulo_number() {
+        assert_eq!(eval_str("7m % 2"), Ok(1.m().into()))
+    }
+
+    #[test]
+    fn angle_modulo_angle() {
+        assert_eq!(eval_str("450deg % 360deg"), Ok(90.deg().into()))
+    }
+
+    #[test]
+    fn length_modulo_zero() {
+        let input = "1m % 0m";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "division by zero".into(),
+                Span::from((0, 7, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn number_divided_by_zero() {
+        let input = "1 / 0";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "division by zero".into(),
+                Span::from((0, 5, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn negative_literal_to_power() {
+        assert_eq!(eval_str("-2 ^ 2"), Ok((-4.0).into()));
+        assert_eq!(eval_str("0 - 2 ^ 2"), Ok((-4.0).into()));
+        assert_eq!(eval_str("(-2) ^ 2"), Ok(4.0.into()));
+        assert_eq!(eval_str("2 ^ -2"), Ok(0.25.into()))
+    }
+
+    #[test]
+    fn negative_length_to_power() {
+        assert_eq!(eval_str("-3m ^ 2"), Ok(Area::from_m2(-9.).into()))
+    }
+
+    #[test]
+    fn zero_to_negative_power() {
+        let input = "0 ^ -1";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "division by zero".into(),
+                Span::from((0, 6, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn length_squared() {
+        assert_eq!(eval_str("3m ^ 2"), Ok(Area::from_m2(9.).into()))
+    }
+
+    #[test]
+    fn length_cubed() {
+        assert_eq!(eval_str("2m ** 3"), Ok(Volume::from_m3(8.).into()))
+    }
+
+    #[test]
+    fn area_to_the_power_of_a_half() {
+        assert_eq!(eval_str("(4m * 4m) ^ 0.5"), Ok(4.m().into()))
+    }
+
+    #[test]
+    fn length_to_fractional_power() {
+        let input = "2m ^ 0.5";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::InvalidArgument(
+                "Length to the power of 0.5 has no supported unit".into(),
+                Span::from((0, 8, input))
+            ))
+        )
+    }
+
+    #[test]
+    fn power_with_length_exponent() {
+        let input = "2 ^ 2m";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::Arguments {
+                should: vec!["Number".into()],
+                is: vec!["Length".into()],
+                span: Span::from((0, 6, input))
+            })
+        )
+    }
+
+    #[test]
+    fn add_mixed_dimensions() {
+        let input = "1m + 2";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::IncompatibleOperands {
+                operation: "add".into(),
+                left: "Length".into(),
+                right: "Number".into(),
+                span: Span::from((0, 6, input))
+            })
+        )
+    }
+
+    #[test]
+    fn subtract_mixed_dimensions() {
+        let input = "1m - 2m * 2m";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::IncompatibleOperands {
+                operation: "subtract".into(),
+                left: "Length".into(),
+                right: "Area".into(),
+                span: Span::from((0, 12, input))
+            })
+        )
+    }
+
+    #[test]
+    fn unrepresentable_result() {
+        let input = "1m / (2m * 2m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::IncompatibleOperands {
+                operation: "divide".into(),
+                left: "Length".into(),
+                right: "Area".into(),
+                span: Span::from((0, 14, input))
+            })
+        )
+    }
+
+    #[test]
+    fn add_non_scalar() {
+        let input = "1m + Cube(1m)";
+        assert_eq!(
+            eval_str(input),
+            Err(Error::IncompatibleOperands {
+                operation: "add".into(),
+                left: "Length".into(),
+                right: "Part".into(),
+                span: Span::from((0, 13, input))
+            })
+        )
+    }
+}
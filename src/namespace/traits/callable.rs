@@ -1,13 +1,59 @@
 use downcast_rs::{Downcast, impl_downcast};
 use dyn_clone::{DynClone, clone_trait_object};
 
-use crate::{Error, Span, namespace::Member};
+use crate::{
+    Error, Span,
+    namespace::{DefaultUnits, Member},
+};
 
 pub trait Callable: DynClone + Downcast {
     #[allow(unused_variables)]
     fn call(&self, args: &[Member], span: Span) -> Result<Member, Error> {
         Err(Error::NotCallable(self.full_name(), span))
     }
+    /// Call with the given arguments, converting bare numbers into the default units where the
+    /// argument matching expects a `Length` or an `Angle`.
+    ///
+    /// Returns the result together with the indices of the arguments that were converted and the
+    /// symbols of the units they were converted into.
+    fn call_with_defaults(
+        &self,
+        args: &[Member],
+        span: Span,
+        defaults: &DefaultUnits,
+    ) -> Result<(Member, Vec<(usize, String)>), Error> {
+        let error = match self.call(args, span.clone()) {
+            Ok(member) => return Ok((member, vec![])),
+            Err(error) => error,
+        };
+        let Error::Arguments { should, .. } = &error else {
+            return Err(error);
+        };
+        if should.len() != args.len() {
+            return Err(error);
+        }
+
+        let mut converted_args = args.to_vec();
+        let mut converted = vec![];
+        for (i, (should, arg)) in should.iter().zip(args).enumerate() {
+            if let (Some(number), Some((symbol, unit))) =
+                (arg.downcast_ref::<f64>(), defaults.for_type(should))
+            {
+                converted_args[i] = unit.member(*number);
+                converted.push((i, symbol.clone()));
+            }
+        }
+        if converted.is_empty() {
+            return Err(error);
+        }
+        Ok((self.call(&converted_args, span)?, converted))
+    }
+    /// Return the argument and return types this callable accepts, as used by the type checker.
+    ///
+    /// An empty vector means the types are not known statically, so calls are not checked.
+    fn signatures(&self) -> Vec<Signature> {
+        vec![]
+    }
     fn full_name(&self) -> String;
     fn short_name(&self) -> String {
         match self.full_name().split(".").last() {
@@ -17,6 +63,30 @@ pub trait Callable: DynClone + Downcast {
     }
 }
 
+/// The type names of the arguments of a callable and the type name of its result.
+///
+/// # Example
+/// ```rust
+/// use oden::Signature;
+///
+/// let signature = Signature::new(&["Length", "Length"], "Sketch");
+/// assert_eq!(signature.args, vec!["Length".to_string(), "Length".to_string()]);
+/// assert_eq!(signature.returns, "Sketch")
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Signature {
+    pub args: Vec<String>,
+    pub returns: String,
+}
+impl Signature {
+    pub fn new(args: &[&str], returns: &str) -> Self {
+        Self {
+            args: args.iter().map(|arg| arg.to_string()).collect(),
+            returns: returns.into(),
+        }
+    }
+}
+
 clone_trait_object!(Callable);
 impl_downcast!(Callable);
 
@@ -120,7 +190,7 @@ macro_rules! match_args {
                         span: $span.clone(),
                     }),
                 }?;
-                let a3 = match t3.downcast_ref::<$second>() {
+                let a3 = match t3.downcast_ref::<$third>() {
                     Some(a) => Ok(*a),
                     None => Err($crate::Error::Arguments {
                         should: vec![
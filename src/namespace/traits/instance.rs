@@ -3,23 +3,26 @@ use std::fmt::Debug;
 use downcast_rs::{Downcast, impl_downcast};
 use dyn_clone::{DynClone, clone_trait_object};
 
-use crate::{Error, Span, namespace::traits::Callable};
+use crate::{Error, Span, errors::suggest, namespace::traits::Callable};
 pub trait Instance: Debug + DynClone + Downcast {
     fn methods(&self) -> Vec<Box<dyn Callable>> {
         vec![]
     }
     fn method(&self, name: String, span: &Span) -> Result<Box<dyn Callable>, Error> {
-        match self
-            .methods()
-            .iter()
-            .filter(|m| m.short_name() == name)
-            .last()
-        {
+        let methods = self.methods();
+        match methods.iter().rev().find(|m| m.short_name() == name) {
             Some(m) => Ok(m.clone()),
-            None => Err(Error::UnknownMethod(name, span.clone())),
+            None => {
+                let suggestion = suggest(&name, methods.iter().map(|m| m.short_name()));
+                Err(Error::UnknownMethod(name, suggestion, span.clone()))
+            }
         }
     }
     fn type_name(&self) -> String;
+    /// Return the text representing this instance in format strings and printed output.
+    fn display(&self) -> String {
+        format!("<{}>", self.type_name())
+    }
     fn eq(&self, other: &dyn Instance) -> bool;
 }
 
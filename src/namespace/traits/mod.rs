@@ -2,6 +2,6 @@ mod callable;
 mod instance;
 mod r#type;
 
-pub use callable::Callable;
+pub use callable::{Callable, Signature};
 pub use instance::Instance;
 pub use r#type::Type;
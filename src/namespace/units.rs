@@ -0,0 +1,158 @@
+use std::{collections::HashMap, f64::consts::PI};
+
+use anvil::{Angle, Length};
+
+use crate::namespace::Member;
+
+/// The dimension of a unit together with its size in the SI unit of that dimension.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Unit {
+    /// A unit of length, given in meters per unit.
+    Length(f64),
+    /// A unit of angle, given in radians per unit.
+    Angle(f64),
+}
+impl Unit {
+    /// Return a Member representing `value` of this unit.
+    pub fn member(&self, value: f64) -> Member {
+        match self {
+            Unit::Length(meters) => Length::from_m(value * meters).into(),
+            Unit::Angle(radians) => Angle::from_rad(value * radians).into(),
+        }
+    }
+}
+
+/// A table of all units that can be used in literals, keyed by their symbol.
+///
+/// The default table contains the builtin units. Embedders can register their own units, which
+/// are then available to literals evaluated with this table.
+///
+/// # Example
+/// ```rust
+/// use oden::{Unit, UnitTable};
+///
+/// let mut units = UnitTable::default();
+/// units.register("ly", Unit::Length(9.4607e15));
+/// assert_eq!(units.get("ly"), Some(&Unit::Length(9.4607e15)));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct UnitTable(HashMap<String, Unit>);
+impl Default for UnitTable {
+    /// Return a UnitTable with the builtin units.
+    fn default() -> Self {
+        Self(
+            [
+                ("km", Unit::Length(1000.)),
+                ("m", Unit::Length(1.)),
+                ("dm", Unit::Length(0.1)),
+                ("cm", Unit::Length(0.01)),
+                ("mm", Unit::Length(0.001)),
+                ("um", Unit::Length(1e-6)),
+                ("ft", Unit::Length(0.3048)),
+                ("in", Unit::Length(0.0254)),
+                ("thou", Unit::Length(2.54e-5)),
+                ("mil", Unit::Length(2.54e-5)),
+                ("turn", Unit::Angle(2. * PI)),
+                ("rad", Unit::Angle(1.)),
+                ("deg", Unit::Angle(PI / 180.)),
+                ("grad", Unit::Angle(PI / 200.)),
+            ]
+            .map(|(symbol, unit)| (symbol.to_string(), unit))
+            .into(),
+        )
+    }
+}
+impl UnitTable {
+    /// Return a UnitTable with the builtin units.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Return the unit with the given symbol.
+    pub fn get(&self, symbol: &str) -> Option<&Unit> {
+        self.0.get(symbol)
+    }
+    /// Return the symbols of all units in the table.
+    pub fn symbols(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
+    /// Add a unit to the table.
+    /// If a unit with this symbol was already present, it is replaced and returned.
+    pub fn register(&mut self, symbol: impl Into<String>, unit: Unit) -> Option<Unit> {
+        self.0.insert(symbol.into(), unit)
+    }
+}
+
+/// The units that bare numbers are converted to when a `Length` or `Angle` is expected.
+///
+/// These are set by the `units` directive of a file, e.g. `units mm`.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct DefaultUnits {
+    length: Option<(String, Unit)>,
+    angle: Option<(String, Unit)>,
+}
+impl DefaultUnits {
+    /// Use the given unit for bare numbers where its dimension is expected.
+    pub fn set(&mut self, symbol: &str, unit: Unit) {
+        match unit {
+            Unit::Length(_) => self.length = Some((symbol.into(), unit)),
+            Unit::Angle(_) => self.angle = Some((symbol.into(), unit)),
+        }
+    }
+    /// Return the symbol and unit for a type name as produced by the argument matching.
+    pub fn for_type(&self, type_name: &str) -> Option<&(String, Unit)> {
+        match type_name {
+            "Length" => self.length.as_ref(),
+            "Angle" => self.angle.as_ref(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use anvil::{IntoAngle, IntoLength};
+
+    use super::*;
+    use crate::eval_str;
+
+    fn assert_length(input: &str, meters: f64) {
+        let result = eval_str(input).unwrap();
+        let length = result.downcast_ref::<Length>().unwrap();
+        assert!((length.m() - meters).abs() < 1e-12, "{input}: {length:?}");
+    }
+
+    fn assert_angle(input: &str, radians: f64) {
+        let result = eval_str(input).unwrap();
+        let angle = result.downcast_ref::<Angle>().unwrap();
+        assert!((angle.rad() - radians).abs() < 1e-12, "{input}: {angle:?}");
+    }
+
+    #[test]
+    fn imperial_lengths() {
+        assert_length("2in", 0.0508);
+        assert_length("1ft", 0.3048);
+        assert_length("10thou", 0.000254);
+        assert_length("10mil", 0.000254);
+    }
+
+    #[test]
+    fn metric_lengths() {
+        assert_eq!(eval_str("3km"), Ok(3000.m().into()));
+        assert_length("5dm", 0.5);
+        assert_length("20um", 0.00002);
+    }
+
+    #[test]
+    fn angles() {
+        assert_angle("0.5turn", PI);
+        assert_angle("100grad", PI / 2.);
+        assert_eq!(eval_str("1rad"), Ok(1.rad().into()));
+    }
+
+    #[test]
+    fn register() {
+        let mut units = UnitTable::new();
+        assert_eq!(units.register("ly", Unit::Length(9.4607e15)), None);
+        assert_eq!(units.get("ly").unwrap().member(2.), 18.9214e15.m().into());
+    }
+}
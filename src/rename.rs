@@ -0,0 +1,423 @@
+use crate::{
+    PartNamespace,
+    errors::Error,
+    syntax::{
+        ExprKind, Expression, FormatPart, Source, Span, Statement, StmtKind, Token, TokenKind,
+        parse_source_tree, tokenize,
+    },
+};
+
+/// Names that have a meaning in the syntax and can not be used as variable names.
+const KEYWORDS: [&str; 4] = ["for", "in", "part", "units"];
+
+/// Return the spans of all references to the variable `name`, including the assignments to it.
+///
+/// Only identifiers that refer to the variable are found: method names, record fields and
+/// variables of list comprehensions with the same name are left out. Returns the first error if
+/// the input can not be parsed.
+///
+/// # Example
+/// ```rust
+/// use oden::{Span, find_references};
+///
+/// let input = "size = 5mm\nsizes = [s * 2 for s in [size]]\nprint(size)";
+/// assert_eq!(
+///     find_references(input, "size"),
+///     Ok(vec![
+///         Span::from((0, 4, input)),
+///         Span::from((36, 40, input)),
+///         Span::from((49, 53, input)),
+///     ])
+/// )
+/// ```
+pub fn find_references(input: &str, name: &str) -> Result<Vec<Span>, Error> {
+    Ok(resolve(&Source::new(input), name, None)?.references)
+}
+
+/// Rename the variable `old` to `new` in an oden part that is loaded into a &str and return the
+/// new text.
+///
+/// Everything but the references returned by `find_references` is kept as it is, including
+/// comments and whitespace. The rename is refused if `old` is not assigned in the input or if
+/// `new` would shadow or be shadowed by another name.
+///
+/// # Example
+/// ```rust
+/// use oden::rename_input;
+///
+/// let input = "
+///     part Box:
+///         wall = 2mm  // wall thickness
+///         part.add(Cube(wall).move_to(wall, 0m, 0m))
+/// ";
+/// assert_eq!(
+///     rename_input(input, "wall", "wall_thickness"),
+///     Ok("
+///     part Box:
+///         wall_thickness = 2mm  // wall thickness
+///         part.add(Cube(wall_thickness).move_to(wall_thickness, 0m, 0m))
+/// "
+///     .into())
+/// )
+/// ```
+pub fn rename_input(input: &str, old: &str, new: &str) -> Result<String, Error> {
+    rename_input_with_namespace(input, old, new, &PartNamespace::new())
+}
+
+/// Rename a variable like `rename_input`, refusing names that are defined in a custom namespace.
+pub fn rename_input_with_namespace(
+    input: &str,
+    old: &str,
+    new: &str,
+    namespace: &PartNamespace,
+) -> Result<String, Error> {
+    rename_source(Source::new(input), old, new, namespace)
+}
+
+/// Rename a variable in an oden source file like `rename_input_with_namespace` and return the new
+/// text.
+///
+/// The spans of the errors point into the source, so that they are displayed with its path.
+pub fn rename_source(
+    source: Source,
+    old: &str,
+    new: &str,
+    namespace: &PartNamespace,
+) -> Result<String, Error> {
+    if !is_variable_name(new) {
+        return Err(Error::InvalidRename(
+            format!("{} is not a valid variable name", new),
+            None,
+        ));
+    }
+    let resolved = resolve(&source, old, Some(new))?;
+    if resolved.assignment.is_none() {
+        return Err(Error::InvalidRename(
+            format!("{} is not a variable assigned in this file", old),
+            None,
+        ));
+    }
+    if old == new {
+        return Ok(source.text().into());
+    }
+    if namespace.get(&new.into()).is_some() {
+        return Err(Error::InvalidRename(
+            format!(
+                "renaming {} to {} would shadow the builtin {}",
+                old, new, new
+            ),
+            None,
+        ));
+    }
+    if let Some(span) = resolve(&source, new, None)?.references.first() {
+        return Err(Error::InvalidRename(
+            format!(
+                "renaming {} to {} would clash with the variable {}",
+                old, new, new
+            ),
+            Some(span.clone()),
+        ));
+    }
+    if let Some(span) = resolved.captured {
+        return Err(Error::InvalidRename(
+            format!(
+                "renaming {} to {} would be shadowed by the list comprehension variable {}",
+                old, new, new
+            ),
+            Some(span),
+        ));
+    }
+
+    let mut output = source.text().to_string();
+    for span in resolved.references.iter().rev() {
+        let (start, end) = span.bytes();
+        output.replace_range(start..end, new);
+    }
+    Ok(output)
+}
+
+/// Return true if the name could be assigned to in an oden file.
+fn is_variable_name(name: &str) -> bool {
+    let is_ident = match tokenize(name).as_deref() {
+        Ok([token]) => token.kind() == &TokenKind::Ident(name.into()),
+        _ => false,
+    };
+    is_ident && !KEYWORDS.contains(&name)
+}
+
+/// The references to a variable found in an oden file.
+struct Resolved {
+    /// The spans of the references in the order of the source.
+    references: Vec<Span>,
+    /// The span of the first assignment to the variable.
+    assignment: Option<Span>,
+    /// The first reference that is inside of a list comprehension binding the new name.
+    captured: Option<Span>,
+}
+
+fn resolve(source: &Source, name: &str, new: Option<&str>) -> Result<Resolved, Error> {
+    let (tree, errors) = parse_source_tree(source.clone());
+    if let Some(error) = errors.into_iter().next() {
+        return Err(error);
+    }
+    let mut resolver = Resolver {
+        name,
+        new,
+        idents: vec![],
+        bound: vec![],
+        resolved: Resolved {
+            references: vec![],
+            assignment: None,
+            captured: None,
+        },
+    };
+    for node in tree.statements() {
+        let tokens = node.statement_tokens();
+        resolver.idents = ident_tokens(&tokens);
+        resolver.statement(&node.to_statement()?);
+    }
+    resolver
+        .resolved
+        .references
+        .sort_by_key(|span| span.start());
+    Ok(resolver.resolved)
+}
+
+/// Return all identifier tokens, including those inside of format strings.
+fn ident_tokens(tokens: &[Token]) -> Vec<Token> {
+    let mut idents = vec![];
+    for token in tokens {
+        match token.kind() {
+            TokenKind::Ident(_) => idents.push(token.clone()),
+            TokenKind::FormatString(parts) => {
+                for part in parts {
+                    if let FormatPart::Expr(tokens) = part {
+                        idents.extend(ident_tokens(tokens))
+                    }
+                }
+            }
+            _ => (),
+        }
+    }
+    idents
+}
+
+struct Resolver<'a> {
+    name: &'a str,
+    new: Option<&'a str>,
+    /// The identifier tokens of the current statement.
+    idents: Vec<Token>,
+    /// The variables of the list comprehensions around the current expression.
+    bound: Vec<String>,
+    resolved: Resolved,
+}
+
+impl Resolver<'_> {
+    fn statement(&mut self, statement: &Statement) {
+        match &statement.0 {
+            StmtKind::Assignment(name, expr) => {
+                if name == self.name {
+                    // Comments are not identifiers, so the first identifier is the assigned name.
+                    let span = self.idents[0].span().clone();
+                    self.resolved.assignment.get_or_insert(span.clone());
+                    self.resolved.references.push(span);
+                }
+                self.expression(expr);
+            }
+            StmtKind::Expr(expr) => self.expression(expr),
+            StmtKind::Empty | StmtKind::PartDeclaration(_) | StmtKind::Units(_) => (),
+        }
+    }
+
+    fn expression(&mut self, expr: &Expression) {
+        match expr.kind() {
+            ExprKind::Comprehension {
+                element,
+                variable,
+                iterable,
+            } => {
+                self.expression(iterable);
+                self.bound.push(variable.clone());
+                self.expression(element);
+                self.bound.pop();
+            }
+            ExprKind::FormatString(parts) | ExprKind::List(parts) => {
+                for part in parts {
+                    self.expression(part)
+                }
+            }
+            ExprKind::Function { name, args } => {
+                self.reference(name, expr);
+                for arg in args {
+                    self.expression(arg)
+                }
+            }
+            ExprKind::Ident(name) => self.reference(name, expr),
+            ExprKind::Literal(_) | ExprKind::Str(_) => (),
+            ExprKind::Method { receiver, args, .. } => {
+                self.expression(receiver);
+                for arg in args {
+                    self.expression(arg)
+                }
+            }
+            ExprKind::Record(fields) => {
+                for (_, value) in fields {
+                    self.expression(value)
+                }
+            }
+        }
+    }
+
+    /// Record the identifier of an `Ident` or `Function` expression if it refers to the variable.
+    fn reference(&mut self, name: &str, expr: &Expression) {
+        if name != self.name || self.bound.iter().any(|bound| bound == name) {
+            return;
+        }
+        // The identifier is the first one with the name in the expression. Parentheses around
+        // the expression are part of its span, so the identifier does not have to start it.
+        let Some(token) = self.idents.iter().find(|token| {
+            token.span().start() >= expr.span().start()
+                && token.kind() == &TokenKind::Ident(name.into())
+                && !self.resolved.references.contains(token.span())
+        }) else {
+            return;
+        };
+        let span = token.span().clone();
+        if self
+            .new
+            .is_some_and(|new| self.bound.iter().any(|bound| bound == new))
+        {
+            self.resolved.captured.get_or_insert(span.clone());
+        }
+        self.resolved.references.push(span);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn texts(input: &str, name: &str) -> Vec<String> {
+        find_references(input, name)
+            .unwrap()
+            .iter()
+            .map(|span| format!("{}:{}", span.start(), span.text()))
+            .collect()
+    }
+
+    #[test]
+    fn references_in_nested_expressions() {
+        let input = "a = 1mm\nb = {x: (a), y: [a, 2 * a]}\nprint(f\"{a * 2}\", b.x)";
+        assert_eq!(
+            texts(input, "a"),
+            vec!["0:a", "17:a", "25:a", "32:a", "45:a"]
+        )
+    }
+
+    #[test]
+    fn method_names_and_fields_are_not_references() {
+        let input = "add = 1mm\nx = {add: add}\npart Box:\n    part.add(Cube(x.add))";
+        assert_eq!(texts(input, "add"), vec!["0:add", "20:add"])
+    }
+
+    #[test]
+    fn comprehension_variable_is_not_a_reference() {
+        let input = "s = 1mm\nsizes = [s * 2 for s in [s]]";
+        assert_eq!(texts(input, "s"), vec!["0:s", "33:s"])
+    }
+
+    #[test]
+    fn called_variable_is_a_reference() {
+        let input = "shape = Cube\nprint(shape(1mm))";
+        assert_eq!(texts(input, "shape"), vec!["0:shape", "19:shape"])
+    }
+
+    #[test]
+    fn rename_keeps_comments_and_non_ascii_text() {
+        let input = "höhe = 1mm // höhe\nprint(f\"höhe={höhe}\")";
+        assert_eq!(
+            rename_input(input, "höhe", "größe"),
+            Ok("größe = 1mm // höhe\nprint(f\"höhe={größe}\")".into())
+        )
+    }
+
+    #[test]
+    fn rename_unknown_variable() {
+        let input = "size = 1mm\nprint(Cube(size))";
+        assert_eq!(
+            rename_input(input, "Cube", "Box"),
+            Err(Error::InvalidRename(
+                "Cube is not a variable assigned in this file".into(),
+                None
+            ))
+        );
+        assert_eq!(
+            rename_input(input, "width", "depth"),
+            Err(Error::InvalidRename(
+                "width is not a variable assigned in this file".into(),
+                None
+            ))
+        )
+    }
+
+    #[test]
+    fn rename_to_invalid_name() {
+        for new in ["1mm", "a b", "for", "part", ""] {
+            assert_eq!(
+                rename_input("size = 1mm", "size", new),
+                Err(Error::InvalidRename(
+                    format!("{} is not a valid variable name", new),
+                    None
+                ))
+            )
+        }
+    }
+
+    #[test]
+    fn rename_to_builtin() {
+        assert_eq!(
+            rename_input("size = 1mm", "size", "Cube"),
+            Err(Error::InvalidRename(
+                "renaming size to Cube would shadow the builtin Cube".into(),
+                None
+            ))
+        )
+    }
+
+    #[test]
+    fn rename_to_existing_variable() {
+        let input = "a = 1mm\nb = 2mm\nprint(a + b)";
+        assert_eq!(
+            rename_input(input, "a", "b"),
+            Err(Error::InvalidRename(
+                "renaming a to b would clash with the variable b".into(),
+                Some(Span::from((8, 9, input)))
+            ))
+        )
+    }
+
+    #[test]
+    fn rename_into_comprehension_variable() {
+        let input = "a = 1mm\nsizes = [a * b for b in [1, 2]]";
+        assert_eq!(
+            rename_input(input, "a", "b"),
+            Err(Error::InvalidRename(
+                "renaming a to b would be shadowed by the list comprehension variable b".into(),
+                Some(Span::from((17, 18, input)))
+            ))
+        );
+        assert_eq!(
+            rename_input(input, "a", "c"),
+            Ok("c = 1mm\nsizes = [c * b for b in [1, 2]]".into())
+        )
+    }
+
+    #[test]
+    fn rename_with_parse_error() {
+        let input = "a = 1mm\nb = $a";
+        assert_eq!(
+            rename_input(input, "a", "c"),
+            Err(Error::UnexpectedSymbol(Span::from((12, 13, input))))
+        )
+    }
+}
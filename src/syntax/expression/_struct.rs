@@ -6,6 +6,26 @@ pub struct Expression(pub ExprKind, pub Span);
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum ExprKind {
+    /// A list built by evaluating an expression for every element of another list.
+    ///
+    /// # Example
+    /// ```oden
+    /// [Cube(size) for size in [1mm, 2mm, 3mm]]
+    /// ```
+    Comprehension {
+        element: Box<Expression>,
+        variable: String,
+        iterable: Box<Expression>,
+    },
+
+    /// A string with interpolated expressions, evaluated by concatenating the text of all parts.
+    ///
+    /// # Example
+    /// ```oden
+    /// f"width={width}"
+    /// ```
+    FormatString(Vec<Expression>),
+
     /// Reference to a variable namespace (like 'part' or 'length').
     Ident(String),
 
@@ -17,6 +37,14 @@ pub enum ExprKind {
     /// ```
     Function { name: String, args: Vec<Expression> },
 
+    /// A list literal.
+    ///
+    /// # Example
+    /// ```oden
+    /// [1mm, 2mm, 3mm]
+    /// ```
+    List(Vec<Expression>),
+
     /// A literal definition (like a length or a number).
     ///
     /// # Example
@@ -37,6 +65,22 @@ pub enum ExprKind {
         method: String,
         args: Vec<Expression>,
     },
+
+    /// A record literal grouping named values, whose fields are accessed like `screw.d`.
+    ///
+    /// # Example
+    /// ```oden
+    /// {d: 3mm, head_d: 5.5mm}
+    /// ```
+    Record(Vec<(String, Expression)>),
+
+    /// A string literal.
+    ///
+    /// # Example
+    /// ```oden
+    /// "front plate"
+    /// ```
+    Str(String),
 }
 impl Expression {
     /// Return the ExprKind of this Expression (the first field).
@@ -1,34 +1,133 @@
 use super::{ExprKind, Expression};
-use crate::{Member, PartNamespace, errors::Error};
+use crate::{
+    Callable, List, Member, PartNamespace, Record, Span,
+    errors::{Error, suggest},
+};
 
 impl Expression {
     /// Evaluate an expression to a Member.
     pub fn evaluate(&self, namespace: &PartNamespace) -> Result<Member, Error> {
         let span = self.span().clone();
         match self.kind() {
-            ExprKind::Literal(val) => Member::from_str(val, span),
+            ExprKind::Comprehension {
+                element,
+                variable,
+                iterable,
+            } => {
+                let iterable = iterable.evaluate(namespace)?;
+                let Some(List(items)) = iterable.downcast_ref::<List>() else {
+                    return Err(Error::InvalidArgument(
+                        format!(
+                            "can only iterate over a List, not a {}",
+                            iterable.type_name()
+                        ),
+                        span,
+                    ));
+                };
+                let mut inner = namespace.clone();
+                let mut evaluated = vec![];
+                for item in items {
+                    inner.insert(variable.clone(), item.clone());
+                    evaluated.push(element.evaluate(&inner)?);
+                }
+                Ok(List(evaluated).into())
+            }
+            ExprKind::FormatString(parts) => {
+                let mut text = String::new();
+                for part in parts {
+                    text.push_str(&part.evaluate(namespace)?.display());
+                }
+                Ok(text.into())
+            }
+            ExprKind::List(elements) => Ok(List(eval_args(elements, namespace)?).into()),
+            ExprKind::Record(fields) => {
+                let mut record = Record::default();
+                for (name, expr) in fields {
+                    if record.get(name).is_some() {
+                        return Err(Error::InvalidArgument(
+                            format!("field {} is defined more than once", name),
+                            span,
+                        ));
+                    }
+                    record.0.push((name.clone(), expr.evaluate(namespace)?));
+                }
+                Ok(record.into())
+            }
+            ExprKind::Str(text) => Ok(text.clone().into()),
+            ExprKind::Literal(val) => Member::from_str_with_units(val, namespace.units(), span),
             ExprKind::Ident(key) => match namespace.get(key) {
                 Some(val) => Ok(val.clone()),
-                None => Err(Error::UnknownVariable(key.to_owned(), span)),
+                None => Err(Error::UnknownVariable(
+                    key.to_owned(),
+                    suggest(key, namespace.names()),
+                    span,
+                )),
             },
             ExprKind::Function { name, args } => match namespace.get(name) {
-                Some(Member::Type(t)) => t.call(&eval_args(args, namespace)?, span),
-                _ => Err(Error::UnknownFunction(name.to_owned(), span)),
+                Some(Member::Type(t)) => call(t.as_ref(), args, span, namespace),
+                _ => {
+                    let functions = namespace
+                        .names()
+                        .into_iter()
+                        .filter(|name| matches!(namespace.get(name), Some(Member::Type(_))));
+                    Err(Error::UnknownFunction(
+                        name.to_owned(),
+                        suggest(name, functions),
+                        span,
+                    ))
+                }
             },
             ExprKind::Method {
                 receiver,
                 method,
                 args,
-            } => receiver.evaluate(namespace)?.method(
-                method.into(),
-                &eval_args(args, namespace)?,
-                &span,
-            ),
+            } => {
+                let receiver = receiver.evaluate(namespace)?;
+                match receiver.callable_method(method.into(), &span) {
+                    Ok(callable) => call(callable.as_ref(), args, span, namespace),
+                    Err(Error::UnknownMethod(..)) if args.is_empty() => {
+                        field(&receiver, method, span)
+                    }
+                    Err(error) => Err(error),
+                }
+            }
         }
     }
 }
 
-fn eval_args(args: &Vec<Expression>, namespace: &PartNamespace) -> Result<Vec<Member>, Error> {
+/// Call a callable and record a warning for every bare number that was converted into a default
+/// unit.
+fn call<C: Callable + ?Sized>(
+    callable: &C,
+    args: &[Expression],
+    span: Span,
+    namespace: &PartNamespace,
+) -> Result<Member, Error> {
+    let defaults = namespace.default_units();
+    let (result, converted) =
+        callable.call_with_defaults(&eval_args(args, namespace)?, span, defaults)?;
+    for (i, unit) in converted {
+        namespace.implicit_unit_used(&unit, args[i].span().clone());
+    }
+    Ok(result)
+}
+
+/// Look up a field of a record, which is what `.name` without a matching method evaluates to.
+fn field(receiver: &Member, name: &str, span: Span) -> Result<Member, Error> {
+    let Some(record) = receiver.downcast_ref::<Record>() else {
+        return Err(Error::UnknownMethod(
+            name.into(),
+            suggest(name, receiver.method_names()),
+            span,
+        ));
+    };
+    match record.get(name) {
+        Some(value) => Ok(value.clone()),
+        None => Err(Error::UnknownField(name.into(), span)),
+    }
+}
+
+fn eval_args(args: &[Expression], namespace: &PartNamespace) -> Result<Vec<Member>, Error> {
     let mut evaluated = vec![];
 
     for arg in args {
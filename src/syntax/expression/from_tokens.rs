@@ -1,48 +1,132 @@
 use super::{ExprKind, Expression};
 use crate::{
     errors::Error,
-    syntax::{Span, Token, TokenKind, span::merge_token_span},
+    syntax::{FormatPart, Span, Token, TokenKind, span::merge_token_span},
 };
 
 impl Expression {
     /// Construct an Expression from a vector of tokens.
-    pub fn from_tokens(tokens: &Vec<Token>) -> Result<Self, Error> {
-        let span = merge_token_span(tokens);
+    pub fn from_tokens(tokens: &[Token]) -> Result<Self, Error> {
+        let Some(span) = merge_token_span(tokens) else {
+            return Err(Error::ExpectedExpression(Span::from((0, 0))));
+        };
+        let tokens = remove_encompassing_parenthesis(tokens);
         match extract_token_kinds(tokens).as_slice() {
+            _ if tokens_are_math_expression(tokens) => math_from_tokens(tokens, span),
+            _ if tokens_are_index(tokens) => index_from_tokens(tokens, span),
             _ if tokens_are_method_call(tokens) => method_from_tokens(tokens, span),
+            [TokenKind::LBracket, ..] => list_from_tokens(tokens, span),
+            [TokenKind::LBrace, ..] => record_from_tokens(tokens, span),
             [TokenKind::Literal(value)] => Ok(Expression(ExprKind::Literal(value.clone()), span)),
+            [TokenKind::Str(text)] => Ok(Expression(ExprKind::Str(text.clone()), span)),
+            [TokenKind::FormatString(parts)] => format_string_from_parts(parts, span),
             [TokenKind::Ident(name)] => Ok(Expression(ExprKind::Ident(name.clone()), span)),
-            [TokenKind::Ident(name), TokenKind::LParen, ..] => function_from_tokens(name, tokens),
-            _ if tokens_are_math_expression(tokens) => math_from_tokens(tokens, span),
-            _ => todo!(),
+            [TokenKind::Ident(name), TokenKind::LParen, ..] => {
+                function_from_tokens(name, tokens, span)
+            }
+            _ => Err(Error::ExpectedExpression(span)),
         }
     }
 }
 
+/// Parse the tokens of an operand or receiver, reporting the span of the enclosing expression if
+/// there are none, like for the right operand of `1 +`.
+fn sub_expression(tokens: &[Token], span: &Span) -> Result<Expression, Error> {
+    if tokens.is_empty() {
+        return Err(Error::ExpectedExpression(span.clone()));
+    }
+    Expression::from_tokens(tokens)
+}
+
 fn extract_token_kinds(tokens: &[Token]) -> Vec<TokenKind> {
     tokens.iter().map(|t| t.kind().clone()).collect()
 }
 
-fn tokens_are_method_call(tokens: &Vec<Token>) -> bool {
+/// Return how a token changes the nesting depth of parentheses and brackets.
+fn depth_change(kind: &TokenKind) -> i32 {
+    match kind {
+        TokenKind::LParen | TokenKind::LBracket | TokenKind::LBrace => 1,
+        TokenKind::RParen | TokenKind::RBracket | TokenKind::RBrace => -1,
+        _ => 0,
+    }
+}
+
+fn tokens_are_method_call(tokens: &[Token]) -> bool {
     let mut bracket_depth = 0;
     for token in tokens {
-        let token_kind = token.kind().clone();
-        match (bracket_depth, token_kind) {
-            (_, TokenKind::LParen) => bracket_depth += 1,
-            (_, TokenKind::RParen) => bracket_depth -= 1,
-            (0, TokenKind::Dot) => return true,
-            _ => (),
+        if bracket_depth == 0 && token.kind() == &TokenKind::Dot {
+            return true;
         }
+        bracket_depth += depth_change(token.kind());
     }
     false
 }
 
+/// Return true if the tokens end with an index into a value, like `xs[0]`.
+fn tokens_are_index(tokens: &[Token]) -> bool {
+    matches!(opening_of_last_bracket(tokens), Some(i) if i > 0)
+}
+
+/// Return the position of the '[' matching a ']' at the end of the tokens.
+fn opening_of_last_bracket(tokens: &[Token]) -> Option<usize> {
+    if tokens.last()?.kind() != &TokenKind::RBracket {
+        return None;
+    }
+    let mut bracket_depth = 0;
+    for (i, token) in tokens.iter().enumerate().rev() {
+        bracket_depth -= depth_change(token.kind());
+        if bracket_depth == 0 {
+            return Some(i);
+        }
+    }
+    None
+}
+
+/// Return true if the tokens contain an operator outside of any parentheses.
 fn tokens_are_math_expression(tokens: &[Token]) -> bool {
-    let token_kinds = extract_token_kinds(tokens);
-    token_kinds.contains(&TokenKind::Plus)
-        || token_kinds.contains(&TokenKind::Minus)
-        || token_kinds.contains(&TokenKind::Asterisk)
-        || token_kinds.contains(&TokenKind::Slash)
+    let mut bracket_depth = 0;
+    for token in tokens {
+        if bracket_depth == 0 && operator(token.kind()).is_some() {
+            return true;
+        }
+        bracket_depth += depth_change(token.kind());
+    }
+    false
+}
+
+/// Return the method an operator is desugared to and its precedence (higher binds tighter).
+///
+/// The precedences are documented in the operator table of the README.
+fn operator(kind: &TokenKind) -> Option<(&'static str, u8)> {
+    match kind {
+        TokenKind::Ampersand => Some(("intersect", 0)),
+        TokenKind::Plus => Some(("add", 1)),
+        TokenKind::Minus => Some(("subtract", 1)),
+        TokenKind::Asterisk => Some(("multiply", 2)),
+        TokenKind::Slash => Some(("divide", 2)),
+        TokenKind::Percent => Some(("modulo", 2)),
+        TokenKind::Caret | TokenKind::DoubleAsterisk => Some(("power", 3)),
+        _ => None,
+    }
+}
+
+/// Check if a method is the one that an operator is desugared to.
+pub(crate) fn is_operator_method(method: &str) -> bool {
+    matches!(
+        method,
+        "intersect" | "add" | "subtract" | "multiply" | "divide" | "modulo" | "power"
+    )
+}
+
+fn format_string_from_parts(parts: &[FormatPart], span: Span) -> Result<Expression, Error> {
+    let mut expressions = vec![];
+    for part in parts {
+        expressions.push(match part {
+            FormatPart::Text(text) => Expression(ExprKind::Str(text.clone()), span.clone()),
+            FormatPart::Expr(tokens) => Expression::from_tokens(tokens)?,
+        });
+    }
+    Ok(Expression(ExprKind::FormatString(expressions), span))
 }
 
 fn method_from_tokens(tokens: &[Token], span: Span) -> Result<Expression, Error> {
@@ -56,7 +140,7 @@ fn method_from_tokens(tokens: &[Token], span: Span) -> Result<Expression, Error>
     };
     Ok(Expression(
         ExprKind::Method {
-            receiver: Box::new(Expression::from_tokens(&receiver_tokens)?),
+            receiver: Box::new(sub_expression(&receiver_tokens, &span)?),
             method: method.to_owned(),
             args: call_args_from_tokens(&call_tokens)?,
         },
@@ -64,129 +148,255 @@ fn method_from_tokens(tokens: &[Token], span: Span) -> Result<Expression, Error>
     ))
 }
 
-fn function_from_tokens(name: &String, tokens: &[Token]) -> Result<Expression, Error> {
+fn function_from_tokens(name: &String, tokens: &[Token], span: Span) -> Result<Expression, Error> {
     let call_tokens = tokens[1..].to_vec();
     Ok(Expression(
         ExprKind::Function {
             name: name.to_owned(),
             args: call_args_from_tokens(&call_tokens)?,
         },
-        merge_token_span(tokens),
+        merge_token_span(tokens).unwrap_or(span),
     ))
 }
 
 fn math_from_tokens(tokens: &[Token], span: Span) -> Result<Expression, Error> {
-    let mut left_side_tokens = vec![];
-    let mut right_side_tokens = vec![];
-    let mut method = String::new();
+    // The expression is split at the operator that binds the loosest. For left-associative
+    // operators this is the last one of the lowest precedence, for right-associative operators
+    // the first one.
+    let mut split: Option<(usize, &str, u8)> = None;
     let mut bracket_level = 0;
 
-    let tokens = remove_encompassing_parenthesis(tokens);
     for (i, token) in tokens.iter().enumerate() {
-        match (token.kind(), bracket_level) {
-            (TokenKind::LParen, _) => bracket_level += 1,
-            (TokenKind::RParen, _) => bracket_level -= 1,
-            (TokenKind::Plus, 0) => {
-                method = "add".into();
-                left_side_tokens = tokens[..i].into();
-                right_side_tokens = tokens[i + 1..].into();
-            }
-            (TokenKind::Minus, 0) => {
-                method = "subtract".into();
-                left_side_tokens = tokens[..i].into();
-                right_side_tokens = tokens[i + 1..].into();
-            }
-            (TokenKind::Asterisk, 0) => {
-                if &method == "add" || &method == "subtract" {
-                    continue;
-                }
-                method = "multiply".into();
-                left_side_tokens = tokens[..i].into();
-                right_side_tokens = tokens[i + 1..].into();
-            }
-            (TokenKind::Slash, 0) => {
-                if &method == "add" || &method == "subtract" {
-                    continue;
-                }
-                method = "divide".into();
-                left_side_tokens = tokens[..i].into();
-                right_side_tokens = tokens[i + 1..].into();
-            }
-            _ => (),
+        let depth = bracket_level;
+        bracket_level += depth_change(token.kind());
+        let (0, Some((method, precedence))) = (depth, operator(token.kind())) else {
+            continue;
+        };
+        let right_associative = method == "power";
+        match split {
+            Some((_, _, p)) if p < precedence => (),
+            Some((_, _, p)) if p == precedence && right_associative => (),
+            _ => split = Some((i, method, precedence)),
         }
     }
+
+    let Some((i, method, _)) = split else {
+        return Err(Error::ExpectedExpression(span));
+    };
+    if method == "power"
+        && let [base] = &tokens[..i]
+        && let TokenKind::Literal(literal) = base.kind()
+        && let Some(magnitude) = literal.strip_prefix('-')
+    {
+        return negated_power(base.span(), magnitude, &tokens[i + 1..], span);
+    }
     Ok(Expression(
         ExprKind::Method {
-            receiver: Box::new(Expression::from_tokens(&left_side_tokens)?),
-            method,
-            args: vec![Expression::from_tokens(&right_side_tokens)?],
+            receiver: Box::new(sub_expression(&tokens[..i], &span)?),
+            method: method.into(),
+            args: vec![sub_expression(&tokens[i + 1..], &span)?],
         },
         span,
     ))
 }
 
+/// Parse the power of a negative literal, like `-2 ^ 2`, as the negated power `-(2 ^ 2)`.
+///
+/// The tokenizer makes the minus part of the literal, but it binds looser than the power. As
+/// there is no negation method, the power is multiplied by `-1` instead.
+fn negated_power(
+    base: &Span,
+    magnitude: &str,
+    exponent: &[Token],
+    span: Span,
+) -> Result<Expression, Error> {
+    let Span(start, end, source) = base.clone();
+    let power = Expression(
+        ExprKind::Method {
+            receiver: Box::new(Expression(
+                ExprKind::Literal(magnitude.into()),
+                Span(start + 1, end, source.clone()),
+            )),
+            method: "power".into(),
+            args: vec![sub_expression(exponent, &span)?],
+        },
+        Span(start + 1, span.end(), source.clone()),
+    );
+    Ok(Expression(
+        ExprKind::Method {
+            receiver: Box::new(power),
+            method: "multiply".into(),
+            args: vec![Expression(
+                ExprKind::Literal("-1".into()),
+                Span(start, start + 1, source),
+            )],
+        },
+        span,
+    ))
+}
+
+/// Remove parentheses that enclose all tokens, like in `(1 + 2)` but not in `(1) + (2)`.
 fn remove_encompassing_parenthesis(tokens: &[Token]) -> &[Token] {
     let token_kinds = extract_token_kinds(tokens);
     if (token_kinds.first(), token_kinds.last())
-        == (Some(&TokenKind::LParen), Some(&TokenKind::RParen))
+        != (Some(&TokenKind::LParen), Some(&TokenKind::RParen))
     {
-        &tokens[1..tokens.len() - 1]
-    } else {
-        tokens
+        return tokens;
+    }
+
+    let mut bracket_depth = 0;
+    for (i, kind) in token_kinds.iter().enumerate() {
+        match kind {
+            TokenKind::LParen => bracket_depth += 1,
+            TokenKind::RParen => bracket_depth -= 1,
+            _ => (),
+        }
+        if bracket_depth == 0 && i < token_kinds.len() - 1 {
+            return tokens;
+        }
     }
+    remove_encompassing_parenthesis(&tokens[1..tokens.len() - 1])
 }
 
-fn call_args_from_tokens(call_tokens: &Vec<Token>) -> Result<Vec<Expression>, Error> {
-    let mut args = vec![];
-    let mut arg_tokens: Vec<Token> = vec![];
+fn call_args_from_tokens(call_tokens: &[Token]) -> Result<Vec<Expression>, Error> {
+    if call_tokens.first().map(|t| t.kind()) != Some(&TokenKind::LParen) {
+        return Ok(vec![]);
+    }
     let mut bracket_depth = 0;
-    for token in call_tokens {
-        let token_kind = token.kind().clone();
-        match (bracket_depth, token_kind) {
-            (0, TokenKind::LParen) => bracket_depth += 1,
-            (0, _) => (),
-            (1, TokenKind::Comma) => {
-                if !arg_tokens.is_empty() {
-                    args.push(Expression::from_tokens(&arg_tokens)?);
-                }
-                arg_tokens = vec![];
+    for (i, token) in call_tokens.iter().enumerate() {
+        bracket_depth += depth_change(token.kind());
+        if bracket_depth == 0 {
+            return expressions_separated_by_comma(&call_tokens[1..i]);
+        }
+    }
+    expressions_separated_by_comma(&call_tokens[1..])
+}
+
+/// Parse the expressions in a comma separated sequence, like the arguments of a call.
+fn expressions_separated_by_comma(tokens: &[Token]) -> Result<Vec<Expression>, Error> {
+    split_at_top_level_commas(tokens)
+        .into_iter()
+        .map(Expression::from_tokens)
+        .collect()
+}
+
+/// Split tokens at the commas that are not enclosed by brackets, skipping empty items.
+fn split_at_top_level_commas(tokens: &[Token]) -> Vec<&[Token]> {
+    let mut items = vec![];
+    let mut start = 0;
+    let mut bracket_depth = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if bracket_depth == 0 && token.kind() == &TokenKind::Comma {
+            if i > start {
+                items.push(&tokens[start..i]);
             }
-            (1, TokenKind::RParen) => {
-                if !arg_tokens.is_empty() {
-                    args.push(Expression::from_tokens(&arg_tokens)?);
-                }
-                arg_tokens = vec![];
-                bracket_depth -= 1;
+            start = i + 1;
+        }
+        bracket_depth += depth_change(token.kind());
+    }
+    if tokens.len() > start {
+        items.push(&tokens[start..]);
+    }
+    items
+}
+
+fn index_from_tokens(tokens: &[Token], span: Span) -> Result<Expression, Error> {
+    let Some(open) = opening_of_last_bracket(tokens) else {
+        return Err(Error::ExpectedExpression(span));
+    };
+    Ok(Expression(
+        ExprKind::Method {
+            receiver: Box::new(sub_expression(&tokens[..open], &span)?),
+            method: "get".into(),
+            args: vec![sub_expression(&tokens[open + 1..tokens.len() - 1], &span)?],
+        },
+        span,
+    ))
+}
+
+fn list_from_tokens(tokens: &[Token], span: Span) -> Result<Expression, Error> {
+    if opening_of_last_bracket(tokens) != Some(0) {
+        return Err(Error::ExpectedExpression(span));
+    }
+    let inner = &tokens[1..tokens.len() - 1];
+
+    let mut bracket_depth = 0;
+    for (i, token) in inner.iter().enumerate() {
+        if bracket_depth == 0 && token.kind() == &TokenKind::Ident("for".into()) {
+            return comprehension_from_tokens(&inner[..i], &inner[i + 1..], span);
+        }
+        bracket_depth += depth_change(token.kind());
+    }
+
+    Ok(Expression(
+        ExprKind::List(expressions_separated_by_comma(inner)?),
+        span,
+    ))
+}
+
+/// Construct a list comprehension from the tokens before and after the `for`.
+fn comprehension_from_tokens(
+    element: &[Token],
+    clause: &[Token],
+    span: Span,
+) -> Result<Expression, Error> {
+    let variable = match extract_token_kinds(clause).as_slice() {
+        [TokenKind::Ident(variable), TokenKind::Ident(keyword), _, ..] if keyword == "in" => {
+            variable.clone()
+        }
+        _ => return Err(Error::ExpectedIdentifyer(span)),
+    };
+    if element.is_empty() {
+        return Err(Error::ExpectedExpression(span));
+    }
+    Ok(Expression(
+        ExprKind::Comprehension {
+            element: Box::new(Expression::from_tokens(element)?),
+            variable,
+            iterable: Box::new(Expression::from_tokens(&clause[2..])?),
+        },
+        span,
+    ))
+}
+
+fn record_from_tokens(tokens: &[Token], span: Span) -> Result<Expression, Error> {
+    let mut bracket_depth = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        bracket_depth += depth_change(token.kind());
+        if bracket_depth == 0 && i < tokens.len() - 1 {
+            return Err(Error::ExpectedExpression(span));
+        }
+    }
+    if tokens.last().map(|t| t.kind()) != Some(&TokenKind::RBrace) {
+        return Err(Error::ExpectedExpression(span));
+    }
+
+    let mut fields = vec![];
+    for item in split_at_top_level_commas(&tokens[1..tokens.len() - 1]) {
+        match extract_token_kinds(item).as_slice() {
+            [TokenKind::Ident(name), TokenKind::Colon, _, ..] => {
+                fields.push((name.clone(), Expression::from_tokens(&item[2..])?))
             }
-            (_, TokenKind::LParen) => {
-                bracket_depth += 1;
-                arg_tokens.push(token.clone());
+            [TokenKind::Ident(_), TokenKind::Colon] => {
+                let item_span = merge_token_span(item).unwrap_or_else(|| span.clone());
+                return Err(Error::ExpectedExpression(item_span));
             }
-            (_, TokenKind::RParen) => {
-                bracket_depth -= 1;
-                arg_tokens.push(token.clone());
+            _ => {
+                let item_span = merge_token_span(item).unwrap_or_else(|| span.clone());
+                return Err(Error::ExpectedIdentifyer(item_span));
             }
-            _ => arg_tokens.push(token.clone()),
         }
     }
-    Ok(args)
+    Ok(Expression(ExprKind::Record(fields), span))
 }
 
 fn split_at_last_top_level_dot(tokens: &[Token]) -> (Vec<Token>, Vec<Token>) {
     let mut tokens_after_last_top_level_dot = vec![];
     let mut bracket_depth = 0;
     for token in tokens.iter().rev() {
-        match (bracket_depth, token.kind()) {
-            (_, TokenKind::LParen) => {
-                bracket_depth += 1;
-            }
-            (_, TokenKind::RParen) => {
-                bracket_depth -= 1;
-            }
-            (0, TokenKind::Dot) => {
-                break;
-            }
-            _ => (),
+        bracket_depth += depth_change(token.kind());
+        if bracket_depth == 0 && token.kind() == &TokenKind::Dot {
+            break;
         }
         tokens_after_last_top_level_dot.push(token.clone());
     }
@@ -212,6 +422,47 @@ mod tests {
         Token(t, Span::empty())
     }
 
+    #[test]
+    fn no_tokens() {
+        assert_eq!(
+            Expression::from_tokens(&[]),
+            Err(Error::ExpectedExpression(Span::from((0, 0))))
+        )
+    }
+
+    #[test]
+    fn missing_operands() {
+        for input in ["1 +", "* 2", ".x", "()", "xs[]"] {
+            assert_eq!(
+                crate::eval_str(input),
+                Err(Error::ExpectedExpression(Span::from((
+                    0,
+                    input.len(),
+                    input
+                )))),
+                "{}",
+                input
+            )
+        }
+    }
+
+    #[test]
+    fn negative_literal_to_power() {
+        let input = "-2 ^ 3";
+        assert_eq!(crate::eval_str(input), crate::eval_str("2 ^ 3 * -1"));
+        let statement = crate::Statement::from_tokens(&crate::tokenize(input).unwrap()).unwrap();
+        assert_eq!(
+            crate::debug_statements(&[statement]),
+            "Expr 1:1-1:7
+  Method multiply 1:1-1:7
+    Method power 1:2-1:7
+      Literal 2 1:2-1:3
+      Literal 3 1:6-1:7
+    Literal -1 1:1-1:2
+"
+        )
+    }
+
     #[test]
     fn numeric() {
         let tokens = vec![token(TokenKind::Literal("3.14".into()))];
@@ -443,4 +694,147 @@ mod tests {
             ))
         )
     }
+
+    #[test]
+    fn operator_between_calls() {
+        let tokens = vec![
+            token(TokenKind::Ident("Cube".into())),
+            token(TokenKind::LParen),
+            token(TokenKind::Literal("1m".into())),
+            token(TokenKind::RParen),
+            token(TokenKind::Plus),
+            token(TokenKind::Ident("Sphere".into())),
+            token(TokenKind::LParen),
+            token(TokenKind::Literal("1m".into())),
+            token(TokenKind::RParen),
+        ];
+        assert_eq!(
+            Expression::from_tokens(&tokens),
+            Ok(Expression(
+                ExprKind::Method {
+                    receiver: Box::new(Expression(
+                        ExprKind::Function {
+                            name: "Cube".into(),
+                            args: vec![Expression::lit("1m")]
+                        },
+                        Span::empty()
+                    )),
+                    method: "add".into(),
+                    args: vec![Expression(
+                        ExprKind::Function {
+                            name: "Sphere".into(),
+                            args: vec![Expression::lit("1m")]
+                        },
+                        Span::empty()
+                    )]
+                },
+                Span::empty()
+            ))
+        )
+    }
+
+    #[test]
+    fn list() {
+        let tokens = vec![
+            token(TokenKind::LBracket),
+            token(TokenKind::Literal("1m".into())),
+            token(TokenKind::Comma),
+            token(TokenKind::Ident("x".into())),
+            token(TokenKind::RBracket),
+        ];
+        assert_eq!(
+            Expression::from_tokens(&tokens),
+            Ok(Expression(
+                ExprKind::List(vec![Expression::lit("1m"), Expression::ident("x")]),
+                Span::empty()
+            ))
+        )
+    }
+
+    #[test]
+    fn index() {
+        let tokens = vec![
+            token(TokenKind::Ident("xs".into())),
+            token(TokenKind::LBracket),
+            token(TokenKind::Literal("0".into())),
+            token(TokenKind::RBracket),
+        ];
+        assert_eq!(
+            Expression::from_tokens(&tokens),
+            Ok(Expression(
+                ExprKind::Method {
+                    receiver: Box::new(Expression::ident("xs")),
+                    method: "get".into(),
+                    args: vec![Expression::lit("0")]
+                },
+                Span::empty()
+            ))
+        )
+    }
+
+    #[test]
+    fn comprehension() {
+        let tokens = vec![
+            token(TokenKind::LBracket),
+            token(TokenKind::Ident("x".into())),
+            token(TokenKind::Ident("for".into())),
+            token(TokenKind::Ident("x".into())),
+            token(TokenKind::Ident("in".into())),
+            token(TokenKind::Ident("xs".into())),
+            token(TokenKind::RBracket),
+        ];
+        assert_eq!(
+            Expression::from_tokens(&tokens),
+            Ok(Expression(
+                ExprKind::Comprehension {
+                    element: Box::new(Expression::ident("x")),
+                    variable: "x".into(),
+                    iterable: Box::new(Expression::ident("xs")),
+                },
+                Span::empty()
+            ))
+        )
+    }
+
+    #[test]
+    fn record() {
+        let tokens = vec![
+            token(TokenKind::LBrace),
+            token(TokenKind::Ident("d".into())),
+            token(TokenKind::Colon),
+            token(TokenKind::Literal("3mm".into())),
+            token(TokenKind::Comma),
+            token(TokenKind::Ident("inner".into())),
+            token(TokenKind::Colon),
+            token(TokenKind::LBrace),
+            token(TokenKind::RBrace),
+            token(TokenKind::RBrace),
+        ];
+        assert_eq!(
+            Expression::from_tokens(&tokens),
+            Ok(Expression(
+                ExprKind::Record(vec![
+                    ("d".into(), Expression::lit("3mm")),
+                    (
+                        "inner".into(),
+                        Expression(ExprKind::Record(vec![]), Span::empty())
+                    ),
+                ]),
+                Span::empty()
+            ))
+        )
+    }
+
+    #[test]
+    fn record_field_without_name() {
+        let tokens = vec![
+            token(TokenKind::LBrace),
+            token(TokenKind::Literal("3mm".into())),
+            token(TokenKind::RBrace),
+        ];
+        assert_eq!(
+            Expression::from_tokens(&tokens),
+            Err(Error::ExpectedIdentifyer(Span::empty()))
+        )
+    }
 }
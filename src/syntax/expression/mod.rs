@@ -2,6 +2,8 @@ mod _struct;
 mod eval_str;
 mod evaluate;
 mod from_tokens;
+mod references;
 
 pub use _struct::{ExprKind, Expression};
 pub use eval_str::eval_str;
+pub(crate) use from_tokens::is_operator_method;
@@ -0,0 +1,64 @@
+use super::{ExprKind, Expression};
+
+impl Expression {
+    /// Return the names of all variables and functions this expression reads from the namespace.
+    ///
+    /// Variables that are bound by a list comprehension inside the expression are not included.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Expression, tokenize};
+    ///
+    /// let expr = Expression::from_tokens(&tokenize("[Cube(s) for s in sizes]").unwrap()).unwrap();
+    /// assert_eq!(expr.references(), vec!["Cube".to_string(), "sizes".to_string()])
+    /// ```
+    pub fn references(&self) -> Vec<String> {
+        let mut names = vec![];
+        self.collect_references(&mut vec![], &mut names);
+        names
+    }
+
+    fn collect_references(&self, bound: &mut Vec<String>, names: &mut Vec<String>) {
+        let mut add = |name: &String| {
+            if !bound.contains(name) && !names.contains(name) {
+                names.push(name.clone())
+            }
+        };
+        match self.kind() {
+            ExprKind::Comprehension {
+                element,
+                variable,
+                iterable,
+            } => {
+                bound.push(variable.clone());
+                element.collect_references(bound, names);
+                bound.pop();
+                iterable.collect_references(bound, names);
+            }
+            ExprKind::FormatString(parts) | ExprKind::List(parts) => {
+                for part in parts {
+                    part.collect_references(bound, names);
+                }
+            }
+            ExprKind::Function { name, args } => {
+                add(name);
+                for arg in args {
+                    arg.collect_references(bound, names);
+                }
+            }
+            ExprKind::Ident(name) => add(name),
+            ExprKind::Literal(_) | ExprKind::Str(_) => (),
+            ExprKind::Method { receiver, args, .. } => {
+                receiver.collect_references(bound, names);
+                for arg in args {
+                    arg.collect_references(bound, names);
+                }
+            }
+            ExprKind::Record(fields) => {
+                for (_, value) in fields {
+                    value.collect_references(bound, names);
+                }
+            }
+        }
+    }
+}
@@ -2,8 +2,15 @@ mod expression;
 mod span;
 mod statement;
 mod token;
+mod tree;
 
+pub(crate) use expression::is_operator_method;
 pub use expression::{ExprKind, Expression, eval_str};
-pub use span::Span;
-pub use statement::{Statement, separate_tokens_by_statement};
-pub use token::{Token, TokenKind, tokenize};
+pub use span::{Source, Span};
+pub use statement::{Statement, StmtKind};
+pub use token::{
+    FormatPart, Token, TokenKind, tokenize, tokenize_source_with_recovery, tokenize_with_recovery,
+};
+pub use tree::{
+    NodeKind, SyntaxElement, SyntaxNode, Trivia, TriviaKind, parse_source_tree, parse_tree,
+};
@@ -1,4 +1,7 @@
-use std::sync::Arc;
+use std::{
+    path::{Path, PathBuf},
+    sync::Arc,
+};
 
 use super::Token;
 
@@ -16,7 +19,7 @@ use super::Token;
 /// Span::from((start, end, context));
 /// ```
 #[derive(Debug, Clone, PartialEq)]
-pub struct Span(pub usize, pub usize, pub Arc<String>);
+pub struct Span(pub usize, pub usize, pub Arc<Source>);
 impl Span {
     /// Return the start of the Span (the first field).
     ///
@@ -69,25 +72,90 @@ impl Span {
     /// );
     /// ```
     pub fn lines(&self) -> (usize, usize) {
-        let total_lines = self.2.chars().filter(|c| *c == '\n').count();
-        let mut start_line = total_lines;
-        let mut end_line = total_lines;
-
-        let mut current_line = 0;
-        for (i, char) in self.2.chars().enumerate() {
-            if i == self.0 {
-                start_line = current_line;
-            }
-            if i == self.1 {
-                end_line = current_line;
-            }
+        (self.start_line_col().0, self.end_line_col().0)
+    }
 
-            if char == '\n' {
-                current_line += 1;
-            }
-        }
+    /// Return the columns of the start and the end of this span, counted in chars from 1.
+    ///
+    /// ```rust
+    /// use oden::Span;
+    ///
+    /// let input = "part Box:\n    part.add(Cube(1m))";
+    /// assert_eq!(Span::from((14, 18, input)).columns(), (5, 9))
+    /// ```
+    pub fn columns(&self) -> (usize, usize) {
+        (self.start_line_col().1, self.end_line_col().1)
+    }
+
+    /// Return the line and column of the start of this span, both counted from 1.
+    ///
+    /// ```rust
+    /// use oden::Span;
+    ///
+    /// let input = "// größe\nx = 5mm";
+    /// assert_eq!(Span::from((13, 16, input)).start_line_col(), (2, 5))
+    /// ```
+    pub fn start_line_col(&self) -> (usize, usize) {
+        self.2.line_col(self.0)
+    }
+
+    /// Return the line and column of the (exclusive) end of this span, both counted from 1.
+    ///
+    /// ```rust
+    /// use oden::Span;
+    ///
+    /// let input = "// größe\nx = 5mm";
+    /// assert_eq!(Span::from((13, 16, input)).end_line_col(), (2, 8))
+    /// ```
+    pub fn end_line_col(&self) -> (usize, usize) {
+        self.2.line_col(self.1)
+    }
+
+    /// Return the start and the end of this span as byte offsets into the text.
+    ///
+    /// ```rust
+    /// use oden::Span;
+    ///
+    /// let input = "// größe\nx = 5mm";
+    /// assert_eq!(Span::from((9, 10, input)).bytes(), (11, 12))
+    /// ```
+    pub fn bytes(&self) -> (usize, usize) {
+        (self.2.char_to_byte(self.0), self.2.char_to_byte(self.1))
+    }
+
+    /// Return the text of the source this span covers.
+    ///
+    /// ```rust
+    /// use oden::Span;
+    ///
+    /// let span = Span::from((4, 10, "höhe = 5mm"));
+    /// assert_eq!(span.text(), " = 5mm")
+    /// ```
+    pub fn text(&self) -> &str {
+        let (start, end) = self.bytes();
+        &self.2.text()[start..end]
+    }
 
-        (start_line + 1, end_line + 1)
+    /// Return the source file this span points into.
+    pub fn source(&self) -> &Source {
+        &self.2
+    }
+
+    /// Return the location of the start of this span like `part.oden:12:5`, or `None` if the
+    /// source has no path.
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use oden::{Source, Span};
+    ///
+    /// let source = Source::new("part Box:\n    part.add(Cube(1m))").with_path("box.oden");
+    /// let span = Span(14, 18, Arc::new(source));
+    /// assert_eq!(span.location(), Some("box.oden:2:5".into()))
+    /// ```
+    pub fn location(&self) -> Option<String> {
+        let path = self.2.path()?;
+        let (line, column) = self.start_line_col();
+        Some(format!("{}:{}:{}", path.to_string_lossy(), line, column))
     }
 
     /// Return the union of two Spans.
@@ -121,6 +189,7 @@ impl Span {
         let line_numbers = self.lines();
         let lines: Vec<String> = self
             .2
+            .text()
             .split('\n')
             .enumerate()
             .filter(|(i, _)| i >= &(line_numbers.0 - 1) && i <= &(line_numbers.1 - 1))
@@ -132,6 +201,12 @@ impl Span {
         );
 
         let mut output = String::from("\n");
+        if let Some(location) = self.location() {
+            output.push_str(&" ".repeat(line_numbers_length));
+            output.push_str("--> ");
+            output.push_str(&location);
+            output.push('\n');
+        }
         output.push_str(&" ".repeat(line_numbers_length));
         output.push_str(" |\n");
 
@@ -143,7 +218,7 @@ impl Span {
             output.push('\n');
             output.push_str(&" ".repeat(line_numbers_length));
             output.push_str(" |");
-            output.push_str(&" ".repeat(self.first_line_offset()));
+            output.push_str(&" ".repeat(self.columns().0));
             output.push_str(&"^".repeat(self.1 - self.0));
         } else {
             for (i, line) in lines.iter().enumerate() {
@@ -160,45 +235,98 @@ impl Span {
 
         output
     }
-
-    fn first_line_offset(&self) -> usize {
-        let mut first_line_offset = 0;
-        for (i, ch) in self.2.chars().enumerate() {
-            first_line_offset += 1;
-
-            if ch == '\n' {
-                first_line_offset = 0;
-            }
-
-            if i == self.0 {
-                break;
-            }
-        }
-        first_line_offset
-    }
 }
 impl From<(usize, usize)> for Span {
     fn from(value: (usize, usize)) -> Self {
-        Span(value.0, value.1, Arc::new("".into()))
+        Span(value.0, value.1, Arc::new(Source::new("")))
     }
 }
 impl From<(usize, usize, &str)> for Span {
     fn from(value: (usize, usize, &str)) -> Self {
-        Span(value.0, value.1, Arc::new(value.2.into()))
+        Span(value.0, value.1, Arc::new(Source::new(value.2)))
+    }
+}
+
+/// The text of an oden file together with its path, which Spans point into.
+///
+/// Offsets into the text are counted in chars. They can be converted into byte offsets, which
+/// other tools like editors often expect.
+///
+/// ```rust
+/// use oden::Source;
+///
+/// let source = Source::new("// größe\nx = 5mm").with_path("box.oden");
+/// assert_eq!(source.char_to_byte(9), 11);
+/// assert_eq!(source.byte_to_char(11), Some(9));
+/// assert_eq!(source.byte_to_char(6), None); // inside of the two bytes of 'ö'
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Source {
+    path: Option<PathBuf>,
+    text: String,
+}
+impl Source {
+    /// Return a source with the given text but without a path.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            path: None,
+            text: text.into(),
+        }
+    }
+    /// Set the path of the file the text was read from.
+    pub fn with_path(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+    /// Return the path of the file, if it is known.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+    /// Return the text of the file.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    /// Convert a char offset into a byte offset. Offsets past the end return the length of the
+    /// text.
+    pub fn char_to_byte(&self, index: usize) -> usize {
+        self.text
+            .char_indices()
+            .nth(index)
+            .map(|(byte, _)| byte)
+            .unwrap_or(self.text.len())
+    }
+    /// Convert a byte offset into a char offset. Returns `None` if the byte offset is not at the
+    /// boundary of a char.
+    pub fn byte_to_char(&self, byte: usize) -> Option<usize> {
+        if !self.text.is_char_boundary(byte) {
+            return None;
+        }
+        Some(self.text[..byte].chars().count())
+    }
+    /// Return the line and column of a char offset, both counted from 1. Offsets past the end
+    /// return the position after the last char.
+    pub fn line_col(&self, index: usize) -> (usize, usize) {
+        let mut line = 1;
+        let mut column = 1;
+        for ch in self.text.chars().take(index) {
+            if ch == '\n' {
+                line += 1;
+                column = 1;
+            } else {
+                column += 1;
+            }
+        }
+        (line, column)
     }
 }
 
-/// Merge the spans of all tokens together.
-pub fn merge_token_span(tokens: &[Token]) -> Span {
-    let mut span = tokens
-        .first()
-        .expect("tokens should not be empty")
-        .span()
-        .clone();
+/// Merge the spans of all tokens together, or return None if there are no tokens.
+pub fn merge_token_span(tokens: &[Token]) -> Option<Span> {
+    let mut span = tokens.first()?.span().clone();
     tokens
         .iter()
         .for_each(|token| span = span.merge(token.span()));
-    span
+    Some(span)
 }
 
 #[cfg(test)]
@@ -207,7 +335,7 @@ mod tests {
 
     impl Span {
         pub fn empty() -> Self {
-            Span(0, 0, Arc::new("".into()))
+            Span(0, 0, Arc::new(Source::new("")))
         }
     }
 
@@ -299,6 +427,52 @@ part Box:
         )
     }
 
+    #[test]
+    fn print_after_utf8_comment() {
+        let input = "
+// Größe der Kiste: ½ Zoll → 12.7mm
+part Box:
+    x = 12.7mm  // ✓
+        ";
+        let span = Span::from((51, 62, input));
+        assert_eq!(span.start_line_col(), (4, 5));
+        assert_eq!(span.bytes(), (56, 67));
+        assert_eq!(
+            span.print(),
+            "
+  |
+4 |     x = 12.7mm  // ✓
+  |     ^^^^^^^^^^^
+"
+        )
+    }
+
+    #[test]
+    fn print_with_path() {
+        let source = Source::new("part Box:\n    x = 5m  // größe\n").with_path("box.oden");
+        let span = Span(14, 20, Arc::new(source));
+        assert_eq!(
+            span.print(),
+            "
+ --> box.oden:2:5
+  |
+2 |     x = 5m  // größe
+  |     ^^^^^^
+"
+        )
+    }
+
+    #[test]
+    fn byte_and_char_offsets_round_trip() {
+        let source = Source::new("// ½ → ✓\nx");
+        for (i, (byte, _)) in source.text().char_indices().enumerate() {
+            assert_eq!(source.char_to_byte(i), byte);
+            assert_eq!(source.byte_to_char(byte), Some(i));
+        }
+        assert_eq!(source.char_to_byte(100), source.text().len());
+        assert_eq!(source.line_col(9), (2, 1));
+    }
+
     #[test]
     fn print_multiple_lines() {
         let input = "
@@ -40,4 +40,30 @@ pub enum StmtKind {
     /// part MyPart:
     /// ```
     PartDeclaration(String),
+
+    /// A directive setting the unit of bare numbers where a length or an angle is expected.
+    ///
+    /// # Example
+    /// ```oden
+    /// units mm
+    /// box = Cube(5)  // a cube with a side length of 5mm
+    /// ```
+    Units(String),
+}
+impl Statement {
+    /// Return the name of the variable this statement assigns to, if it is an assignment.
+    pub fn binding(&self) -> Option<&str> {
+        match &self.0 {
+            StmtKind::Assignment(name, _) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// Return the names of all variables and functions this statement reads from the namespace.
+    pub fn references(&self) -> Vec<String> {
+        match &self.0 {
+            StmtKind::Assignment(_, expr) | StmtKind::Expr(expr) => expr.references(),
+            StmtKind::Empty | StmtKind::PartDeclaration(_) | StmtKind::Units(_) => vec![],
+        }
+    }
 }
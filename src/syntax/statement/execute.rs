@@ -1,7 +1,7 @@
 use super::_struct::{Statement, StmtKind};
 use crate::{
     PartNamespace,
-    errors::Error,
+    errors::{Error, suggest},
     syntax::expression::{ExprKind, Expression},
 };
 
@@ -13,31 +13,38 @@ impl Statement {
                 namespace.insert(name, expr.evaluate(namespace)?);
             }
             StmtKind::Empty => (),
-            StmtKind::Expr(expr) => match expr.kind().clone() {
-                ExprKind::Method {
-                    receiver,
-                    method: _,
-                    args: _,
-                } => {
-                    namespace.insert(resolve_receiver(*receiver)?, expr.evaluate(namespace)?);
+            StmtKind::Expr(expr) => {
+                let value = expr.evaluate(namespace)?;
+                // Methods like `part.add(...)` update the variable they are called on, while the
+                // result of queries like `part.volume()` has another type and is discarded.
+                if let ExprKind::Method { receiver, .. } = expr.kind()
+                    && let Some(name) = resolve_receiver(receiver)
+                    && namespace
+                        .get(&name)
+                        .is_some_and(|current| current.type_name() == value.type_name())
+                {
+                    namespace.insert(name, value);
                 }
-                _ => todo!(),
-            },
+            }
             StmtKind::PartDeclaration(_) => (),
+            StmtKind::Units(unit) => {
+                if namespace.set_default_unit(&unit).is_none() {
+                    let suggestion = suggest(&unit, namespace.units().symbols());
+                    return Err(Error::UnknownUnit(unit, suggestion, self.1));
+                }
+            }
         }
         Ok(())
     }
 }
 
-fn resolve_receiver(receiver: Expression) -> Result<String, Error> {
-    match receiver.kind().clone() {
-        ExprKind::Ident(name) => Ok(name),
-        ExprKind::Method {
-            receiver,
-            method: _,
-            args: _,
-        } => resolve_receiver(*receiver),
-        _ => todo!(),
+/// Return the variable at the start of a chain of method calls, or None if the chain starts with
+/// another expression like in `Cube(1mm).volume()`.
+fn resolve_receiver(receiver: &Expression) -> Option<String> {
+    match receiver.kind() {
+        ExprKind::Ident(name) => Some(name.clone()),
+        ExprKind::Method { receiver, .. } => resolve_receiver(receiver),
+        _ => None,
     }
 }
 
@@ -45,7 +52,7 @@ fn resolve_receiver(receiver: Expression) -> Result<String, Error> {
 mod tests {
     use super::*;
     use crate::{
-        Member,
+        Member, Unit,
         syntax::{Span, expression::ExprKind},
     };
     use anvil::{Cuboid, IntoLength, Length};
@@ -59,6 +66,29 @@ mod tests {
         assert_eq!(namespace, PartNamespace::new()) // nothing happened
     }
 
+    #[test]
+    fn units_directive() {
+        let statement = Statement(StmtKind::Units("in".into()), Span::empty());
+        let mut namespace = PartNamespace::new();
+
+        assert!(statement.execute(&mut namespace).is_ok());
+        assert_eq!(
+            namespace.default_units().for_type("Length"),
+            Some(&("in".into(), Unit::Length(0.0254)))
+        )
+    }
+
+    #[test]
+    fn units_directive_with_unknown_unit() {
+        let statement = Statement(StmtKind::Units("ly".into()), Span::empty());
+        let mut namespace = PartNamespace::new();
+
+        assert_eq!(
+            statement.execute(&mut namespace),
+            Err(Error::UnknownUnit("ly".into(), None, Span::empty()))
+        )
+    }
+
     #[test]
     fn assignment() {
         let statement = Statement(
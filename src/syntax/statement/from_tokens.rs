@@ -7,12 +7,15 @@ use super::_struct::{Statement, StmtKind};
 
 impl Statement {
     /// Construct a statement from a vector of tokens.
-    pub fn from_tokens(tokens: &Vec<Token>) -> Result<Self, Error> {
-        let span = merge_token_span(tokens);
+    pub fn from_tokens(tokens: &[Token]) -> Result<Self, Error> {
+        let tokens_with_comment = tokens;
         let tokens = filter_out_comment(tokens);
-        if tokens.is_empty() {
-            return Ok(Statement(StmtKind::Empty, span));
-        }
+        let Some(span) = merge_token_span(&tokens) else {
+            return Ok(Statement(
+                StmtKind::Empty,
+                merge_token_span(tokens_with_comment).unwrap_or_else(|| Span::from((0, 0))),
+            ));
+        };
 
         match extract_token_kinds(&tokens).as_slice() {
             [
@@ -20,6 +23,9 @@ impl Statement {
                 TokenKind::Ident(name),
                 TokenKind::Colon,
             ] => Ok(Statement(StmtKind::PartDeclaration(name.clone()), span)),
+            [TokenKind::Ident(keyword), TokenKind::Ident(unit)] if keyword == "units" => {
+                Ok(Statement(StmtKind::Units(unit.clone()), span))
+            }
             [TokenKind::Ident(name), TokenKind::Equal, ..] => {
                 assignment_from_tokens(name, &tokens, span)
             }
@@ -33,20 +39,12 @@ impl Statement {
     }
 }
 
-fn filter_out_comment(tokens: &Vec<Token>) -> Vec<Token> {
-    let mut token_buffer = vec![];
-    let mut is_comment = false;
-    for token in tokens {
-        match token.kind() {
-            TokenKind::DoubleSlash => is_comment = true,
-            TokenKind::LineBreak => is_comment = false,
-            _ => (),
-        }
-        if !is_comment {
-            token_buffer.push(token.clone());
-        }
-    }
-    token_buffer
+fn filter_out_comment(tokens: &[Token]) -> Vec<Token> {
+    tokens
+        .iter()
+        .filter(|token| !matches!(token.kind(), TokenKind::Comment(_)))
+        .cloned()
+        .collect()
 }
 
 fn extract_token_kinds(tokens: &[Token]) -> Vec<TokenKind> {
@@ -56,10 +54,7 @@ fn extract_token_kinds(tokens: &[Token]) -> Vec<TokenKind> {
 fn assignment_from_tokens(name: &str, tokens: &[Token], span: Span) -> Result<Statement, Error> {
     if tokens.len() > 2 {
         Ok(Statement(
-            StmtKind::Assignment(
-                name.to_owned(),
-                Expression::from_tokens(&tokens[2..].to_vec())?,
-            ),
+            StmtKind::Assignment(name.to_owned(), Expression::from_tokens(&tokens[2..])?),
             span,
         ))
     } else {
@@ -93,6 +88,18 @@ mod tests {
         )
     }
 
+    #[test]
+    fn units_directive() {
+        let tokens = vec![
+            token(TokenKind::Ident("units".into())),
+            token(TokenKind::Ident("mm".into())),
+        ];
+        assert_eq!(
+            Statement::from_tokens(&tokens),
+            Ok(Statement(StmtKind::Units("mm".into()), Span::empty()))
+        )
+    }
+
     #[test]
     fn assignment() {
         let tokens = vec![
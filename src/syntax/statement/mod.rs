@@ -1,7 +1,7 @@
 mod _struct;
 mod execute;
 mod from_tokens;
-mod separate_tokens_by_statement;
+mod statement_continues;
 
-pub use _struct::Statement;
-pub use separate_tokens_by_statement::separate_tokens_by_statement;
+pub use _struct::{Statement, StmtKind};
+pub(crate) use statement_continues::statement_continues;
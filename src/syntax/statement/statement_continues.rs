@@ -1,24 +1,10 @@
 use crate::syntax::{Token, TokenKind};
 
-/// Split up the tokens of an oden file by statement.
+/// Check if the statement continues after the token at `pos`.
 ///
-/// The resulting subvectors can then be used in `Statement::from_tokens()`.
-pub fn separate_tokens_by_statement(tokens: Vec<Token>) -> Vec<Vec<Token>> {
-    let mut separated_tokens = vec![];
-    let mut buffer = vec![];
-    for (i, token) in tokens.iter().enumerate() {
-        buffer.push(token.clone());
-
-        if !statement_continues(&tokens, i) {
-            separated_tokens.push(buffer);
-            buffer = vec![];
-        }
-    }
-
-    filter_out_linebreaks(separated_tokens)
-}
-
-fn statement_continues(tokens: &[Token], pos: usize) -> bool {
+/// A statement ends with a line break outside of brackets, unless the next line starts with a
+/// method call.
+pub(crate) fn statement_continues(tokens: &[Token], pos: usize) -> bool {
     let token = match tokens.get(pos) {
         Some(t) => t.kind().clone(),
         None => return false,
@@ -30,8 +16,8 @@ fn statement_continues(tokens: &[Token], pos: usize) -> bool {
     let mut bracket_level = 0;
     for token in tokens[..pos].iter().map(|t| t.kind().clone()) {
         match token {
-            TokenKind::LParen => bracket_level += 1,
-            TokenKind::RParen => bracket_level -= 1,
+            TokenKind::LParen | TokenKind::LBracket | TokenKind::LBrace => bracket_level += 1,
+            TokenKind::RParen | TokenKind::RBracket | TokenKind::RBrace => bracket_level -= 1,
             _ => (),
         }
     }
@@ -45,17 +31,6 @@ fn statement_continues(tokens: &[Token], pos: usize) -> bool {
     true
 }
 
-fn filter_out_linebreaks(v: Vec<Vec<Token>>) -> Vec<Vec<Token>> {
-    v.into_iter()
-        .map(|subv| {
-            subv.into_iter()
-                .filter(|token| token.kind() != &TokenKind::LineBreak)
-                .collect::<Vec<Token>>()
-        })
-        .filter(|subv| !subv.is_empty())
-        .collect()
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -65,6 +40,23 @@ mod tests {
         Token(t, Span::empty())
     }
 
+    /// Split up the tokens by statement and remove the line breaks, like the statement nodes of
+    /// a syntax tree.
+    fn separate_tokens_by_statement(tokens: Vec<Token>) -> Vec<Vec<Token>> {
+        let mut separated_tokens = vec![];
+        let mut buffer = vec![];
+        for (i, token) in tokens.iter().enumerate() {
+            if token.kind() != &TokenKind::LineBreak {
+                buffer.push(token.clone());
+            }
+            if !statement_continues(&tokens, i) && !buffer.is_empty() {
+                separated_tokens.push(buffer);
+                buffer = vec![];
+            }
+        }
+        separated_tokens
+    }
+
     #[test]
     fn single_statement() {
         let tokens = vec![
@@ -103,6 +95,61 @@ mod tests {
         )
     }
 
+    #[test]
+    fn list_over_multiple_lines() {
+        let tokens = vec![
+            token(TokenKind::Ident("sizes".into())),
+            token(TokenKind::Equal),
+            token(TokenKind::LBracket),
+            token(TokenKind::LineBreak),
+            token(TokenKind::Literal("5mm".into())),
+            token(TokenKind::Comma),
+            token(TokenKind::LineBreak),
+            token(TokenKind::Literal("6mm".into())),
+            token(TokenKind::LineBreak),
+            token(TokenKind::RBracket),
+        ];
+        assert_eq!(
+            separate_tokens_by_statement(tokens.clone()),
+            vec![vec![
+                token(TokenKind::Ident("sizes".into())),
+                token(TokenKind::Equal),
+                token(TokenKind::LBracket),
+                token(TokenKind::Literal("5mm".into())),
+                token(TokenKind::Comma),
+                token(TokenKind::Literal("6mm".into())),
+                token(TokenKind::RBracket),
+            ]]
+        )
+    }
+
+    #[test]
+    fn record_over_multiple_lines() {
+        let tokens = vec![
+            token(TokenKind::Ident("screw".into())),
+            token(TokenKind::Equal),
+            token(TokenKind::LBrace),
+            token(TokenKind::LineBreak),
+            token(TokenKind::Ident("d".into())),
+            token(TokenKind::Colon),
+            token(TokenKind::Literal("3mm".into())),
+            token(TokenKind::LineBreak),
+            token(TokenKind::RBrace),
+        ];
+        assert_eq!(
+            separate_tokens_by_statement(tokens.clone()),
+            vec![vec![
+                token(TokenKind::Ident("screw".into())),
+                token(TokenKind::Equal),
+                token(TokenKind::LBrace),
+                token(TokenKind::Ident("d".into())),
+                token(TokenKind::Colon),
+                token(TokenKind::Literal("3mm".into())),
+                token(TokenKind::RBrace),
+            ]]
+        )
+    }
+
     #[test]
     fn two_assignments() {
         let tokens = vec![
@@ -1,4 +1,6 @@
-use super::Span;
+use unicode_ident::{is_xid_continue, is_xid_start};
+
+use super::{Source, Span};
 use crate::errors::Error;
 use std::{
     fmt::{Debug, Display},
@@ -42,46 +44,140 @@ impl Token {
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum TokenKind {
+    /// A '&' symbol.
+    Ampersand,
     /// A '*' symbol.
     Asterisk,
+    /// A '^' symbol.
+    Caret,
     /// A ';' symbol.
     Colon,
     /// A ',' symbol.
     Comma,
+    /// A line comment starting with '//' or a block comment between '/*' and '*/', holding the
+    /// text without the delimiters.
+    Comment(String),
     /// A '.' symbol.
     Dot,
-    /// A '//'.
-    DoubleSlash,
+    /// A '**'.
+    DoubleAsterisk,
     /// A '=' symbol.
     Equal,
+    /// A string literal with interpolated expressions, like `f"width={w}"`.
+    FormatString(Vec<FormatPart>),
     /// A reference to a variable, function, or method.
     Ident(String),
     /// A literal expression that can be evaluated by itself (like a length).
     Literal(String),
     /// A linebreak.
     LineBreak,
+    /// A '{' symbol
+    LBrace,
+    /// A '[' symbol
+    LBracket,
     /// A '(' symbol
     LParen,
     /// A '-' symbol
     Minus,
+    /// A '%' symbol
+    Percent,
     /// A '+' symbol
     Plus,
+    /// A '}' symbol
+    RBrace,
+    /// A ']' symbol
+    RBracket,
     /// A ')' symbol
     RParen,
     /// A '/' symbol
     Slash,
+    /// A string literal with its escape sequences already resolved.
+    Str(String),
+}
+
+/// A piece of a format string, which is either plain text or the tokens of an interpolated
+/// expression.
+#[derive(Debug, Clone, PartialEq)]
+pub enum FormatPart {
+    Text(String),
+    Expr(Vec<Token>),
 }
 
 /// Convert a text input into tokens.
 #[allow(clippy::ptr_arg)]
 pub fn tokenize(input: &str) -> Result<Vec<Token>, Error> {
     let chars: Vec<char> = input.chars().collect();
-    let context: Arc<String> = Arc::new(input.into());
+    let context = Arc::new(Source::new(input));
+    tokenize_from(&chars, 0, context)
+}
+
+/// Convert a text input into tokens, continuing after errors.
+///
+/// Unexpected symbols are skipped. After other errors, like an unterminated string, the rest of
+/// the line is skipped. Returns the tokens together with all errors that occurred.
+///
+/// # Example
+/// ```rust
+/// use oden::{Error, Span, Token, TokenKind, tokenize_with_recovery};
+///
+/// let input = "$x";
+/// assert_eq!(
+///     tokenize_with_recovery(input),
+///     (
+///         vec![Token(TokenKind::Ident("x".into()), Span::from((1, 2, input)))],
+///         vec![Error::UnexpectedSymbol(Span::from((0, 1, input)))]
+///     )
+/// )
+/// ```
+pub fn tokenize_with_recovery(input: &str) -> (Vec<Token>, Vec<Error>) {
+    tokenize_source_with_recovery(Source::new(input))
+}
+
+/// Convert the text of a source file into tokens whose spans know the path of the file,
+/// continuing after errors like `tokenize_with_recovery`.
+pub fn tokenize_source_with_recovery(source: Source) -> (Vec<Token>, Vec<Error>) {
+    tokenize_context_with_recovery(Arc::new(source))
+}
+
+/// Convert the text of a shared source into tokens, continuing after errors.
+pub(crate) fn tokenize_context_with_recovery(context: Arc<Source>) -> (Vec<Token>, Vec<Error>) {
+    let chars: Vec<char> = context.text().chars().collect();
     let mut tokens = Vec::new();
+    let mut errors = Vec::new();
     let mut pos = 0;
 
     while pos < chars.len() {
-        let (token, advance) = parse_next_token(&chars, pos, context.clone())?;
+        match parse_next_token(&chars, pos, context.clone()) {
+            Ok((token, advance)) => {
+                if let Some(t) = token {
+                    tokens.push(t)
+                }
+                pos += advance;
+            }
+            Err(error) => {
+                match error {
+                    Error::UnexpectedSymbol(_) => pos += 1,
+                    _ => {
+                        while pos < chars.len() && chars[pos] != '\n' {
+                            pos += 1
+                        }
+                    }
+                }
+                errors.push(error);
+            }
+        }
+    }
+
+    (tokens, errors)
+}
+
+/// Convert the characters from `start` until the end of `chars` into tokens.
+fn tokenize_from(chars: &[char], start: usize, context: Arc<Source>) -> Result<Vec<Token>, Error> {
+    let mut tokens = Vec::new();
+    let mut pos = start;
+
+    while pos < chars.len() {
+        let (token, advance) = parse_next_token(chars, pos, context.clone())?;
         if let Some(t) = token {
             tokens.push(t)
         }
@@ -94,7 +190,7 @@ pub fn tokenize(input: &str) -> Result<Vec<Token>, Error> {
 fn parse_next_token(
     chars: &[char],
     pos: usize,
-    context: Arc<String>,
+    context: Arc<Source>,
 ) -> Result<(Option<Token>, usize), Error> {
     let start = pos;
     let ch = chars[pos];
@@ -122,6 +218,34 @@ fn parse_next_token(
             )),
             1,
         )),
+        '{' => Ok((
+            Some(Token(
+                TokenKind::LBrace,
+                Span(pos, pos + 1, context.clone()),
+            )),
+            1,
+        )),
+        '}' => Ok((
+            Some(Token(
+                TokenKind::RBrace,
+                Span(pos, pos + 1, context.clone()),
+            )),
+            1,
+        )),
+        '[' => Ok((
+            Some(Token(
+                TokenKind::LBracket,
+                Span(pos, pos + 1, context.clone()),
+            )),
+            1,
+        )),
+        ']' => Ok((
+            Some(Token(
+                TokenKind::RBracket,
+                Span(pos, pos + 1, context.clone()),
+            )),
+            1,
+        )),
         '=' => Ok((
             Some(Token(TokenKind::Equal, Span(pos, pos + 1, context.clone()))),
             1,
@@ -152,9 +276,39 @@ fn parse_next_token(
                 ))
             }
         }
-        '*' => Ok((
+        '*' => {
+            let next_char = chars.get(pos + 1);
+            match next_char {
+                Some('*') => Ok((
+                    Some(Token(
+                        TokenKind::DoubleAsterisk,
+                        Span(pos, pos + 2, context.clone()),
+                    )),
+                    2,
+                )),
+                _ => Ok((
+                    Some(Token(
+                        TokenKind::Asterisk,
+                        Span(pos, pos + 1, context.clone()),
+                    )),
+                    1,
+                )),
+            }
+        }
+        '&' => Ok((
+            Some(Token(
+                TokenKind::Ampersand,
+                Span(pos, pos + 1, context.clone()),
+            )),
+            1,
+        )),
+        '^' => Ok((
+            Some(Token(TokenKind::Caret, Span(pos, pos + 1, context.clone()))),
+            1,
+        )),
+        '%' => Ok((
             Some(Token(
-                TokenKind::Asterisk,
+                TokenKind::Percent,
                 Span(pos, pos + 1, context.clone()),
             )),
             1,
@@ -169,23 +323,71 @@ fn parse_next_token(
         '/' => {
             let next_char = chars.get(pos + 1);
             match next_char {
-                Some('/') => Ok((
-                    Some(Token(
-                        TokenKind::DoubleSlash,
-                        Span(pos, pos + 2, context.clone()),
-                    )),
-                    2,
-                )),
+                Some('/') => {
+                    let mut end = pos + 2;
+                    while end < chars.len() && chars[end] != '\n' {
+                        end += 1;
+                    }
+                    Ok((
+                        Some(Token(
+                            TokenKind::Comment(chars_to_string(chars[pos + 2..end].to_vec())),
+                            Span(start, end, context.clone()),
+                        )),
+                        end - start,
+                    ))
+                }
+                Some('*') => {
+                    let mut end = pos + 2;
+                    while end + 1 < chars.len() && !(chars[end] == '*' && chars[end + 1] == '/') {
+                        end += 1;
+                    }
+                    if end + 1 >= chars.len() {
+                        return Err(Error::UnterminatedComment(Span(
+                            start,
+                            chars.len(),
+                            context.clone(),
+                        )));
+                    }
+                    Ok((
+                        Some(Token(
+                            TokenKind::Comment(chars_to_string(chars[pos + 2..end].to_vec())),
+                            Span(start, end + 2, context.clone()),
+                        )),
+                        end + 2 - start,
+                    ))
+                }
                 _ => Ok((
                     Some(Token(TokenKind::Slash, Span(pos, pos + 1, context.clone()))),
                     1,
                 )),
             }
         }
+        '"' => {
+            let (text, end) = parse_string_text(chars, pos, pos + 1, false, &context)?;
+            Ok((
+                Some(Token(
+                    TokenKind::Str(text),
+                    Span(start, end + 1, context.clone()),
+                )),
+                end + 1 - start,
+            ))
+        }
+        'f' if chars.get(pos + 1) == Some(&'"') => {
+            let (parts, end) = parse_format_string(chars, pos, &context)?;
+            Ok((
+                Some(Token(
+                    TokenKind::FormatString(parts),
+                    Span(start, end, context.clone()),
+                )),
+                end - start,
+            ))
+        }
         ch if ch.is_whitespace() => Ok((None, 1)),
-        ch if ch.is_ascii_alphabetic() => {
+        ch if is_xid_start(ch)
+            || (ch == '_' && chars.get(pos + 1).is_some_and(|c| is_xid_continue(*c))) =>
+        {
             let mut end = pos + 1;
-            while end < chars.len() && (chars[end].is_ascii_alphanumeric() || chars[end] == '_') {
+            while end < chars.len() && is_xid_continue(chars[end]) {
                 end += 1;
             }
 
@@ -213,9 +415,122 @@ fn parse_next_token(
     }
 }
 
+/// Read the text of a string starting at `pos` until its closing quote, or until the start of an
+/// interpolated expression if `is_format` is set.
+///
+/// Returns the text with resolved escape sequences and the position of the character it stopped
+/// at. `quote` is the position of the opening quote and only used for errors.
+fn parse_string_text(
+    chars: &[char],
+    quote: usize,
+    pos: usize,
+    is_format: bool,
+    context: &Arc<Source>,
+) -> Result<(String, usize), Error> {
+    let mut text = String::new();
+    let mut pos = pos;
+    loop {
+        match (chars.get(pos), chars.get(pos + 1)) {
+            (None | Some('\n'), _) => {
+                return Err(Error::UnterminatedString(Span(quote, pos, context.clone())));
+            }
+            (Some('"'), _) => return Ok((text, pos)),
+            (Some('{'), Some('{')) | (Some('}'), Some('}')) if is_format => {
+                text.push(chars[pos]);
+                pos += 2;
+            }
+            (Some('{'), _) if is_format => return Ok((text, pos)),
+            (Some('\\'), Some(escaped)) => {
+                text.push(match escaped {
+                    'n' => '\n',
+                    't' => '\t',
+                    'r' => '\r',
+                    '0' => '\0',
+                    '\\' | '"' | '{' | '}' => *escaped,
+                    _ => {
+                        return Err(Error::InvalidEscape(
+                            *escaped,
+                            Span(pos, pos + 2, context.clone()),
+                        ));
+                    }
+                });
+                pos += 2;
+            }
+            (Some(ch), _) => {
+                text.push(*ch);
+                pos += 1;
+            }
+        }
+    }
+}
+
+/// Parse a format string like `f"width={w}"` starting at the `f`.
+///
+/// Returns its parts and the position after the closing quote.
+fn parse_format_string(
+    chars: &[char],
+    pos: usize,
+    context: &Arc<Source>,
+) -> Result<(Vec<FormatPart>, usize), Error> {
+    let quote = pos + 1;
+    let mut parts = vec![];
+    let mut pos = quote + 1;
+    loop {
+        let (text, stop) = parse_string_text(chars, quote, pos, true, context)?;
+        if !text.is_empty() {
+            parts.push(FormatPart::Text(text));
+        }
+        if chars[stop] == '"' {
+            return Ok((parts, stop + 1));
+        }
+
+        let expr_end = format_expression_end(chars, quote, stop + 1, context)?;
+        let expr_start = stop + 1;
+        let tokens = tokenize_from(&chars[..expr_end], expr_start, context.clone())?;
+        if tokens.is_empty() {
+            return Err(Error::ExpectedExpression(Span(
+                stop,
+                expr_end + 1,
+                context.clone(),
+            )));
+        }
+        parts.push(FormatPart::Expr(tokens));
+        pos = expr_end + 1;
+    }
+}
+
+/// Return the position of the `}` that closes an expression in a format string.
+///
+/// Braces of records and strings in the expression are skipped, so `f"{ {a: 1}.a }"` and
+/// `f"{"}"}"` contain a single expression each.
+fn format_expression_end(
+    chars: &[char],
+    quote: usize,
+    start: usize,
+    context: &Arc<Source>,
+) -> Result<usize, Error> {
+    let mut pos = start;
+    let mut brace_depth = 0;
+    let mut in_string = false;
+    loop {
+        match chars.get(pos) {
+            None | Some('\n') => {
+                return Err(Error::UnterminatedString(Span(quote, pos, context.clone())));
+            }
+            Some('\\') if in_string => pos += 1,
+            Some('"') => in_string = !in_string,
+            Some('{') if !in_string => brace_depth += 1,
+            Some('}') if !in_string && brace_depth == 0 => return Ok(pos),
+            Some('}') if !in_string => brace_depth -= 1,
+            _ => (),
+        }
+        pos += 1;
+    }
+}
+
 fn parse_literal(chars: &[char], pos: usize) -> (String, usize) {
     let mut end = pos + 1;
-    while literal_continues(chars, &end) {
+    while literal_continues(chars, pos, &end) {
         end += 1;
     }
 
@@ -223,7 +538,7 @@ fn parse_literal(chars: &[char], pos: usize) -> (String, usize) {
     (literal.to_string(), end)
 }
 
-fn literal_continues(chars: &[char], pos: &usize) -> bool {
+fn literal_continues(chars: &[char], start: usize, pos: &usize) -> bool {
     if pos >= &chars.len() {
         return false;
     }
@@ -234,9 +549,26 @@ fn literal_continues(chars: &[char], pos: &usize) -> bool {
     if next_char == '.' {
         return true;
     }
+    if (next_char == '-' || next_char == '+') && is_exponent_sign(chars, start, *pos) {
+        return true;
+    }
     false
 }
 
+/// Check if the sign at `pos` belongs to the exponent of a number in scientific notation, like
+/// the '-' in `1.5e-3m`.
+fn is_exponent_sign(chars: &[char], start: usize, pos: usize) -> bool {
+    if !matches!(chars[pos - 1], 'e' | 'E') {
+        return false;
+    }
+    if !chars.get(pos + 1).is_some_and(|c| c.is_ascii_digit()) {
+        return false;
+    }
+    let mantissa = &chars[start..pos - 1];
+    let mantissa = mantissa.strip_prefix(&['-']).unwrap_or(mantissa);
+    !mantissa.is_empty() && mantissa.iter().all(|c| c.is_ascii_digit() || *c == '.')
+}
+
 fn chars_to_string(chars: Vec<char>) -> String {
     let mut string = String::new();
     chars.iter().for_each(|char| string.push(char.to_owned()));
@@ -246,20 +578,30 @@ fn chars_to_string(chars: Vec<char>) -> String {
 impl Display for TokenKind {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
+            TokenKind::Ampersand => write!(f, "Ampersand"),
             TokenKind::Asterisk => write!(f, "Asterisk"),
+            TokenKind::Caret => write!(f, "Caret"),
             TokenKind::Colon => write!(f, "Colon"),
             TokenKind::Comma => write!(f, "Comma"),
+            TokenKind::Comment(text) => write!(f, "Comment({})", text),
             TokenKind::Dot => write!(f, "Dot"),
-            TokenKind::DoubleSlash => write!(f, "DoubleSlash"),
+            TokenKind::DoubleAsterisk => write!(f, "DoubleAsterisk"),
             TokenKind::Equal => write!(f, "Equal"),
+            TokenKind::FormatString(_) => write!(f, "FormatString"),
             TokenKind::Ident(val) => write!(f, "Ident({})", val),
             TokenKind::Literal(val) => write!(f, "Literal({})", val),
             TokenKind::LineBreak => write!(f, "LineBreak"),
+            TokenKind::LBrace => write!(f, "LBrace"),
+            TokenKind::LBracket => write!(f, "LBracket"),
             TokenKind::LParen => write!(f, "LParen"),
             TokenKind::Minus => write!(f, "Minus"),
+            TokenKind::Percent => write!(f, "Percent"),
             TokenKind::Plus => write!(f, "Plus"),
+            TokenKind::RBrace => write!(f, "RBrace"),
+            TokenKind::RBracket => write!(f, "RBracket"),
             TokenKind::RParen => write!(f, "RParen"),
             TokenKind::Slash => write!(f, "Slash"),
+            TokenKind::Str(val) => write!(f, "Str({})", val),
         }
     }
 }
@@ -304,6 +646,31 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_length_literal_with_exponent() {
+        let input = "1.5e-3m";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![Token(
+                TokenKind::Literal("1.5e-3m".into()),
+                Span::from((0, 7, input))
+            )])
+        )
+    }
+
+    #[test]
+    fn test_subtraction_after_unit_ending_in_e() {
+        let input = "1e - 3";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![
+                Token(TokenKind::Literal("1e".into()), Span::from((0, 2, input))),
+                Token(TokenKind::Minus, Span::from((3, 4, input))),
+                Token(TokenKind::Literal("3".into()), Span::from((5, 6, input))),
+            ])
+        )
+    }
+
     #[test]
     fn test_two_identifyers_separated_by_dot() {
         let input = "part.add";
@@ -382,21 +749,150 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_modulo() {
+        let input = "7 % 2";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![
+                Token(TokenKind::Literal("7".into()), Span::from((0, 1, input))),
+                Token(TokenKind::Percent, Span::from((2, 3, input))),
+                Token(TokenKind::Literal("2".into()), Span::from((4, 5, input))),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_power() {
+        let input = "2 ^ 3 ** 4";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![
+                Token(TokenKind::Literal("2".into()), Span::from((0, 1, input))),
+                Token(TokenKind::Caret, Span::from((2, 3, input))),
+                Token(TokenKind::Literal("3".into()), Span::from((4, 5, input))),
+                Token(TokenKind::DoubleAsterisk, Span::from((6, 8, input))),
+                Token(TokenKind::Literal("4".into()), Span::from((9, 10, input))),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_intersection() {
+        let input = "a & b";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![
+                Token(TokenKind::Ident("a".into()), Span::from((0, 1, input))),
+                Token(TokenKind::Ampersand, Span::from((2, 3, input))),
+                Token(TokenKind::Ident("b".into()), Span::from((4, 5, input))),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_list() {
+        let input = "[1, x]";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![
+                Token(TokenKind::LBracket, Span::from((0, 1, input))),
+                Token(TokenKind::Literal("1".into()), Span::from((1, 2, input))),
+                Token(TokenKind::Comma, Span::from((2, 3, input))),
+                Token(TokenKind::Ident("x".into()), Span::from((4, 5, input))),
+                Token(TokenKind::RBracket, Span::from((5, 6, input))),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_record() {
+        let input = "{d: 3mm}";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![
+                Token(TokenKind::LBrace, Span::from((0, 1, input))),
+                Token(TokenKind::Ident("d".into()), Span::from((1, 2, input))),
+                Token(TokenKind::Colon, Span::from((2, 3, input))),
+                Token(TokenKind::Literal("3mm".into()), Span::from((4, 7, input))),
+                Token(TokenKind::RBrace, Span::from((7, 8, input))),
+            ])
+        )
+    }
+
     #[test]
     fn test_comment() {
         let input = "// a_comment";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![Token(
+                TokenKind::Comment(" a_comment".into()),
+                Span::from((0, 12, input))
+            )])
+        )
+    }
+
+    #[test]
+    fn test_comment_with_arbitrary_text() {
+        let input = "x // Höhe in mm, $ & ½\ny";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![
+                Token(TokenKind::Ident("x".into()), Span::from((0, 1, input))),
+                Token(
+                    TokenKind::Comment(" Höhe in mm, $ & ½".into()),
+                    Span::from((2, 22, input))
+                ),
+                Token(TokenKind::LineBreak, Span::from((22, 23, input))),
+                Token(TokenKind::Ident("y".into()), Span::from((23, 24, input))),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_block_comment() {
+        let input = "x /* über\nzwei Zeilen */ y";
         assert_eq!(
             tokenize(input),
             Ok(vec![
-                Token(TokenKind::DoubleSlash, Span::from((0, 2, input))),
+                Token(TokenKind::Ident("x".into()), Span::from((0, 1, input))),
                 Token(
-                    TokenKind::Ident("a_comment".into()),
-                    Span::from((3, 12, input))
+                    TokenKind::Comment(" über\nzwei Zeilen ".into()),
+                    Span::from((2, 24, input))
                 ),
+                Token(TokenKind::Ident("y".into()), Span::from((25, 26, input))),
             ])
         )
     }
 
+    #[test]
+    fn test_unicode_identifiers() {
+        let input = "höhe = _größe";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![
+                Token(TokenKind::Ident("höhe".into()), Span::from((0, 4, input))),
+                Token(TokenKind::Equal, Span::from((5, 6, input))),
+                Token(
+                    TokenKind::Ident("_größe".into()),
+                    Span::from((7, 13, input))
+                ),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_non_identifier_symbols() {
+        let input = "_ ½";
+        assert_eq!(
+            tokenize_with_recovery(input).1,
+            vec![
+                Error::UnexpectedSymbol(Span::from((0, 1, input))),
+                Error::UnexpectedSymbol(Span::from((2, 3, input))),
+            ]
+        )
+    }
+
     #[test]
     fn test_part_declaration() {
         let input = "part Box:";
@@ -503,10 +999,104 @@ mod tests {
 
     #[test]
     fn test_unexpected_symbol() {
-        let input = "&";
+        let input = "$";
         assert_eq!(
             tokenize(input),
             Err(Error::UnexpectedSymbol(Span::from((0, 1, input))))
         )
     }
+
+    #[test]
+    fn test_string() {
+        let input = r#"name = "a // b""#;
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![
+                Token(TokenKind::Ident("name".into()), Span::from((0, 4, input))),
+                Token(TokenKind::Equal, Span::from((5, 6, input))),
+                Token(TokenKind::Str("a // b".into()), Span::from((7, 15, input))),
+            ])
+        )
+    }
+
+    #[test]
+    fn test_format_string() {
+        let input = r#"f"w={w}mm""#;
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![Token(
+                TokenKind::FormatString(vec![
+                    FormatPart::Text("w=".into()),
+                    FormatPart::Expr(vec![Token(
+                        TokenKind::Ident("w".into()),
+                        Span::from((5, 6, input))
+                    )]),
+                    FormatPart::Text("mm".into()),
+                ]),
+                Span::from((0, 10, input))
+            )])
+        )
+    }
+
+    #[test]
+    fn test_identifier_starting_with_f() {
+        let input = "foo";
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![Token(
+                TokenKind::Ident("foo".into()),
+                Span::from((0, 3, input))
+            )])
+        )
+    }
+
+    #[test]
+    fn test_unterminated_string() {
+        let input = "\"abc\nx";
+        assert_eq!(
+            tokenize(input),
+            Err(Error::UnterminatedString(Span::from((0, 4, input))))
+        )
+    }
+
+    #[test]
+    fn test_unterminated_format_expression() {
+        let input = r#"f"{w""#;
+        assert_eq!(
+            tokenize(input),
+            Err(Error::UnterminatedString(Span::from((1, 5, input))))
+        )
+    }
+
+    #[test]
+    fn test_format_expression_with_braces() {
+        let input = r#"f"{ {a: 1}.a }""#;
+        let Ok(tokens) = tokenize(input) else {
+            panic!("format string should be tokenized")
+        };
+        let [Token(TokenKind::FormatString(parts), _)] = tokens.as_slice() else {
+            panic!("expected a single format string, got {:?}", tokens)
+        };
+        let [FormatPart::Expr(expression)] = parts.as_slice() else {
+            panic!("expected a single expression, got {:?}", parts)
+        };
+        assert_eq!(expression.len(), 7);
+        assert_eq!(expression[0].span(), &Span::from((4, 5, input)));
+        assert_eq!(expression[6].span(), &Span::from((11, 12, input)));
+    }
+
+    #[test]
+    fn test_format_expression_with_string() {
+        let input = r#"f"{"}"}""#;
+        assert_eq!(
+            tokenize(input),
+            Ok(vec![Token(
+                TokenKind::FormatString(vec![FormatPart::Expr(vec![Token(
+                    TokenKind::Str("}".into()),
+                    Span::from((3, 6, input))
+                )])]),
+                Span::from((0, 8, input))
+            )])
+        )
+    }
 }
@@ -0,0 +1,387 @@
+use std::{fmt::Display, sync::Arc};
+
+use crate::{
+    errors::Error,
+    syntax::{
+        Source, Span, Statement, Token, TokenKind, statement::statement_continues,
+        token::tokenize_context_with_recovery,
+    },
+};
+
+/// A node of the concrete syntax tree of an oden file.
+///
+/// In contrast to `Statement` and `Expression`, the syntax tree keeps every character of the
+/// source: comments, line breaks, whitespace and even text that could not be tokenized. Printing
+/// a tree returns the exact text it was parsed from, which makes it the basis for tools that
+/// rewrite source files. The statements are derived from it with `SyntaxNode::to_statement`.
+///
+/// # Example
+/// ```rust
+/// use oden::{NodeKind, parse_tree};
+///
+/// let input = "part Box:  // a box\n    part.add(Cube( 1mm ))\n";
+/// let (tree, errors) = parse_tree(input);
+/// assert!(errors.is_empty());
+/// assert_eq!(tree.kind(), NodeKind::File);
+/// assert_eq!(tree.to_string(), input);
+/// assert_eq!(tree.statements()[1].to_string(), "    part.add(Cube( 1mm ))\n");
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct SyntaxNode(pub NodeKind, pub Vec<SyntaxElement>);
+
+/// The kind of a `SyntaxNode`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NodeKind {
+    /// The whole file. It contains the statements and the trivia after the last statement.
+    File,
+    /// A pair of parentheses, brackets or braces with everything between them.
+    Group,
+    /// A statement with the trivia in front of it and the line break that ends it.
+    Statement,
+}
+
+/// A child of a `SyntaxNode`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SyntaxElement {
+    Node(SyntaxNode),
+    Token(Token),
+    Trivia(Trivia),
+}
+
+/// Text between tokens that the tokenizer does not produce a token for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Trivia(pub TriviaKind, pub Span);
+
+/// The kind of `Trivia`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TriviaKind {
+    /// Text that was skipped after a tokenizer error, like an unexpected symbol.
+    Skipped,
+    /// Spaces, tabs and carriage returns.
+    Whitespace,
+}
+
+impl SyntaxNode {
+    /// Return the NodeKind of this node (the first field).
+    pub fn kind(&self) -> NodeKind {
+        self.0
+    }
+
+    /// Return the children of this node (the second field).
+    pub fn children(&self) -> &[SyntaxElement] {
+        &self.1
+    }
+
+    /// Return the span from the first to the last child, or `None` if the node has no children.
+    pub fn span(&self) -> Option<Span> {
+        let first = self.1.first()?.span()?;
+        let last = self.1.last()?.span()?;
+        Some(first.merge(&last))
+    }
+
+    /// Return all tokens in this node and its descendants in the order of the source.
+    pub fn tokens(&self) -> Vec<Token> {
+        let mut tokens = vec![];
+        for child in &self.1 {
+            match child {
+                SyntaxElement::Node(node) => tokens.extend(node.tokens()),
+                SyntaxElement::Token(token) => tokens.push(token.clone()),
+                SyntaxElement::Trivia(_) => (),
+            }
+        }
+        tokens
+    }
+
+    /// Return the statement nodes of a file that contain more than line breaks.
+    pub fn statements(&self) -> Vec<&SyntaxNode> {
+        self.1
+            .iter()
+            .filter_map(|child| match child {
+                SyntaxElement::Node(node) if node.kind() == NodeKind::Statement => Some(node),
+                _ => None,
+            })
+            .filter(|node| !node.statement_tokens().is_empty())
+            .collect()
+    }
+
+    /// Return the tokens a `Statement` is constructed from, which are all tokens except line
+    /// breaks.
+    pub fn statement_tokens(&self) -> Vec<Token> {
+        self.tokens()
+            .into_iter()
+            .filter(|token| token.kind() != &TokenKind::LineBreak)
+            .collect()
+    }
+
+    /// Construct the statement of a statement node.
+    ///
+    /// # Example
+    /// ```rust
+    /// use oden::{Statement, StmtKind, parse_tree};
+    ///
+    /// let (tree, _) = parse_tree("units mm // default units\n");
+    /// let statement = tree.statements()[0].to_statement().unwrap();
+    /// assert_eq!(statement.0, StmtKind::Units("mm".into()))
+    /// ```
+    pub fn to_statement(&self) -> Result<Statement, Error> {
+        Statement::from_tokens(&self.statement_tokens())
+    }
+}
+
+impl SyntaxElement {
+    /// Return the span of this element, or `None` for a node without children.
+    pub fn span(&self) -> Option<Span> {
+        match self {
+            SyntaxElement::Node(node) => node.span(),
+            SyntaxElement::Token(token) => Some(token.span().clone()),
+            SyntaxElement::Trivia(trivia) => Some(trivia.1.clone()),
+        }
+    }
+}
+
+impl Display for SyntaxNode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.span() {
+            Some(span) => write!(f, "{}", span.text()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Parse a text input into a concrete syntax tree, continuing after errors.
+///
+/// Returns the tree together with the errors of the tokenizer. The tree always covers the
+/// complete input.
+pub fn parse_tree(input: &str) -> (SyntaxNode, Vec<Error>) {
+    parse_source_tree(Source::new(input))
+}
+
+/// Parse the text of a source file into a concrete syntax tree whose spans know the path of the
+/// file, continuing after errors like `parse_tree`.
+pub fn parse_source_tree(source: Source) -> (SyntaxNode, Vec<Error>) {
+    let context = Arc::new(source);
+    let chars: Vec<char> = context.text().chars().collect();
+    let (tokens, errors) = tokenize_context_with_recovery(context.clone());
+
+    let mut stack = vec![SyntaxNode(NodeKind::File, vec![])];
+    let mut pos = 0;
+    for (i, token) in tokens.iter().enumerate() {
+        if stack.len() == 1 {
+            stack.push(SyntaxNode(NodeKind::Statement, vec![]));
+        }
+        push_trivia(&mut stack, &chars, pos, token.span().start(), &context);
+
+        let element = SyntaxElement::Token(token.clone());
+        match token.kind() {
+            TokenKind::LParen | TokenKind::LBracket | TokenKind::LBrace => {
+                stack.push(SyntaxNode(NodeKind::Group, vec![element]))
+            }
+            TokenKind::RParen | TokenKind::RBracket | TokenKind::RBrace if stack.len() > 2 => {
+                push_element(&mut stack, element);
+                close_node(&mut stack);
+            }
+            _ => push_element(&mut stack, element),
+        }
+
+        if !statement_continues(&tokens, i) {
+            while stack.len() > 1 {
+                close_node(&mut stack);
+            }
+        }
+        pos = token.span().end();
+    }
+    push_trivia(&mut stack, &chars, pos, chars.len(), &context);
+
+    (stack.remove(0), errors)
+}
+
+fn push_element(stack: &mut [SyntaxNode], element: SyntaxElement) {
+    stack
+        .last_mut()
+        .expect("the file node should always be on the stack")
+        .1
+        .push(element)
+}
+
+/// Move the innermost open node into its parent.
+fn close_node(stack: &mut Vec<SyntaxNode>) {
+    if let Some(node) = stack.pop() {
+        push_element(stack, SyntaxElement::Node(node))
+    }
+}
+
+/// Add the text between two tokens as runs of whitespace and skipped text.
+fn push_trivia(
+    stack: &mut [SyntaxNode],
+    chars: &[char],
+    start: usize,
+    end: usize,
+    context: &Arc<Source>,
+) {
+    let mut run_start = start;
+    for pos in start..end {
+        let next_differs =
+            pos + 1 == end || chars[pos].is_whitespace() != chars[pos + 1].is_whitespace();
+        if next_differs {
+            let kind = match chars[pos].is_whitespace() {
+                true => TriviaKind::Whitespace,
+                false => TriviaKind::Skipped,
+            };
+            let trivia = Trivia(kind, Span(run_start, pos + 1, context.clone()));
+            push_element(stack, SyntaxElement::Trivia(trivia));
+            run_start = pos + 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip(input: &str) {
+        assert_eq!(parse_tree(input).0.to_string(), input)
+    }
+
+    fn kinds(node: &SyntaxNode) -> Vec<String> {
+        node.children()
+            .iter()
+            .map(|child| match child {
+                SyntaxElement::Node(node) => format!("{:?}", node.kind()),
+                SyntaxElement::Token(token) => token.kind().to_string(),
+                SyntaxElement::Trivia(trivia) => format!("{:?}", trivia.0),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn round_trip() {
+        assert_round_trip("");
+        assert_round_trip("\n\n");
+        assert_round_trip("part Box:\n    part.add(Cube(1mm))");
+        assert_round_trip("part Box:\r\n\tpart.add(Cube(1mm))  \r\n");
+        assert_round_trip(
+            "
+            // the size of the box
+            part Box:  /* block
+                          comment */
+                size = [
+                    1mm,  // first
+                    2mm,
+                ]
+                part.add(Cube(size[0]))
+                    .move_to(1mm, 1mm, 1mm)
+            ",
+        );
+    }
+
+    #[test]
+    fn round_trip_non_ascii() {
+        assert_round_trip("part Würfel: // für die Größe\n    höhe = 4mm\n    print(\"😀\")\n");
+    }
+
+    #[test]
+    fn round_trip_with_errors() {
+        assert_round_trip("part Box:\n    size = $4mm ?\n");
+        assert_round_trip("part Box:\n    label = \"box\n    print(label)\n");
+        assert_round_trip("part Box:\n    /* unterminated\n    part.add(Cube(1mm))\n");
+        let (tree, errors) = parse_tree("x = $4mm");
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            kinds(tree.statements()[0]),
+            vec![
+                "Ident(x)",
+                "Whitespace",
+                "Equal",
+                "Whitespace",
+                "Skipped",
+                "Literal(4mm)"
+            ]
+        )
+    }
+
+    #[test]
+    fn statements_over_multiple_lines() {
+        let input = "part Box:  // comment
+    sizes = [
+        1mm,
+        2mm,
+    ]
+
+    part.add(Cube(sizes[0]))
+        .add(Cube(sizes[1]))
+";
+        let (tree, _) = parse_tree(input);
+        let statements: Vec<String> = tree
+            .statements()
+            .iter()
+            .map(|node| node.to_string())
+            .collect();
+        assert_eq!(
+            statements,
+            vec![
+                "part Box:  // comment\n",
+                "    sizes = [\n        1mm,\n        2mm,\n    ]\n",
+                "    part.add(Cube(sizes[0]))\n        .add(Cube(sizes[1]))\n",
+            ]
+        );
+        assert_eq!(tree.statements()[2].statement_tokens().len(), 23)
+    }
+
+    #[test]
+    fn trivia_belongs_to_following_statement() {
+        let (tree, _) = parse_tree("  a = 1 // one\n  b = 2\n  ");
+        assert_eq!(kinds(&tree), vec!["Statement", "Statement", "Whitespace"]);
+        assert_eq!(tree.statements()[0].to_string(), "  a = 1 // one\n");
+        assert_eq!(
+            kinds(tree.statements()[0]),
+            vec![
+                "Whitespace",
+                "Ident(a)",
+                "Whitespace",
+                "Equal",
+                "Whitespace",
+                "Literal(1)",
+                "Whitespace",
+                "Comment( one)",
+                "LineBreak",
+            ]
+        )
+    }
+
+    #[test]
+    fn nested_groups() {
+        let (tree, _) = parse_tree("Cube( f(1mm) )");
+        let statement = tree.statements()[0];
+        assert_eq!(kinds(statement), vec!["Ident(Cube)", "Group"]);
+        let SyntaxElement::Node(group) = &statement.children()[1] else {
+            panic!("expected a group")
+        };
+        assert_eq!(group.to_string(), "( f(1mm) )");
+        assert_eq!(
+            kinds(group),
+            vec![
+                "LParen",
+                "Whitespace",
+                "Ident(f)",
+                "Group",
+                "Whitespace",
+                "RParen"
+            ]
+        )
+    }
+
+    #[test]
+    fn unclosed_group_ends_with_statement() {
+        let (tree, _) = parse_tree("a = (1,\n  2\n");
+        assert_eq!(tree.to_string(), "a = (1,\n  2\n");
+        assert_eq!(kinds(&tree), vec!["Statement"]);
+        assert_eq!(kinds(tree.statements()[0])[4], "Group");
+    }
+
+    #[test]
+    fn spans_know_the_path() {
+        let source = Source::new("a = 1").with_path("a.oden");
+        let (tree, _) = parse_source_tree(source);
+        assert_eq!(tree.span().unwrap().location(), Some("a.oden:1:1".into()))
+    }
+}
@@ -0,0 +1,209 @@
+use anvil::Cuboid;
+use oden::{
+    Diagnostic, Error, PartNamespace, Severity, Span, Warning, check_input,
+    compile_input_with_diagnostics,
+};
+
+fn diagnose(input: &str) -> Vec<Diagnostic> {
+    compile_input_with_diagnostics(input, &mut PartNamespace::new()).1
+}
+
+#[test]
+fn test_continue_after_unexpected_symbols() {
+    let input = "
+        part Box:
+            size = $4mm
+            part.add(Cube(size)) ?
+        ";
+    let (part, diagnostics) = compile_input_with_diagnostics(input, &mut PartNamespace::new());
+    assert_eq!(
+        diagnostics,
+        vec![
+            Error::UnexpectedSymbol(Span::from((38, 39, input))).into(),
+            Error::UnexpectedSymbol(Span::from((76, 77, input))).into(),
+        ]
+    );
+    assert_eq!(part, Cuboid::from_mm(4., 4., 4.))
+}
+
+#[test]
+fn test_continue_after_bad_statement() {
+    let input = "
+        part Box:
+            = 4mm
+            part.add(Cube(4mm))
+            part.add(Sphere(4deg))
+        ";
+    let (part, diagnostics) = compile_input_with_diagnostics(input, &mut PartNamespace::new());
+    assert_eq!(
+        diagnostics,
+        vec![
+            Error::ExpectedIdentifyer(Span::from((31, 36, input))).into(),
+            Error::Arguments {
+                should: vec!["Length".into()],
+                is: vec!["Angle".into()],
+                span: Span::from((90, 102, input)),
+            }
+            .into(),
+        ]
+    );
+    assert_eq!(part, Cuboid::from_mm(4., 4., 4.))
+}
+
+#[test]
+fn test_skip_statements_depending_on_failed_bindings() {
+    let input = "
+        part Box:
+            size = Cube(4deg)
+            print(size.volume() * 2)
+            part.add(size)
+            size = Cube(1mm)
+            part.add(size)
+        ";
+    let (part, diagnostics) = compile_input_with_diagnostics(input, &mut PartNamespace::new());
+    assert_eq!(
+        diagnostics,
+        vec![
+            Error::Arguments {
+                should: vec!["Length".into()],
+                is: vec!["Angle".into()],
+                span: Span::from((38, 48, input)),
+            }
+            .into()
+        ]
+    );
+    assert_eq!(part, Cuboid::from_mm(1., 1., 1.))
+}
+
+#[test]
+fn test_recover_from_incomplete_expressions() {
+    let input = "part Box:
+    Cube(1m).volume()
+    \"abc\".len()
+    Point(1m, 2m).x()
+    x = 1 +
+    part.add(Cube(1m))
+    y = (1
+";
+    let expected: Vec<Diagnostic> = vec![
+        Warning::DiscardedValue(Span::from((14, 31, input))).into(),
+        Warning::DiscardedValue(Span::from((36, 47, input))).into(),
+        Warning::DiscardedValue(Span::from((52, 69, input))).into(),
+        Error::ExpectedExpression(Span::from((78, 81, input))).into(),
+        Error::ExpectedExpression(Span::from((113, 115, input))).into(),
+    ];
+    let (part, diagnostics) = compile_input_with_diagnostics(input, &mut PartNamespace::new());
+    assert_eq!(diagnostics, expected);
+    assert_eq!(part, Cuboid::from_m(1., 1., 1.));
+    assert_eq!(check_input(input), expected);
+}
+
+#[test]
+fn test_unterminated_string_is_reported_once() {
+    let input = "
+        part Box:
+            label = \"box
+            print(label)
+            part.add(Cube(label))
+        ";
+    assert_eq!(
+        diagnostics_severity_and_span(input),
+        vec![(Severity::Error, Some(Span::from((39, 43, input))))]
+    )
+}
+
+#[test]
+fn test_warnings_are_included() {
+    let input = "
+        units mm
+        part Box:
+            part.add(Cube(4))
+            part.add(Cube(4deg))
+        ";
+    let mut namespace = PartNamespace::new();
+    namespace.warn_implicit_units(true);
+    let (_, diagnostics) = compile_input_with_diagnostics(input, &mut namespace);
+    assert_eq!(
+        diagnostics,
+        vec![
+            Warning::ImplicitUnit("mm".into(), Span::from((62, 63, input))).into(),
+            Error::Arguments {
+                should: vec!["Length".into()],
+                is: vec!["Angle".into()],
+                span: Span::from((87, 97, input)),
+            }
+            .into(),
+        ]
+    )
+}
+
+#[test]
+fn test_lints_use_the_builtins_of_the_namespace() {
+    let input = "
+        part Box:
+            wall = 2mm
+            part.add(Cube(wall))
+        ";
+    let mut namespace = PartNamespace::new();
+    namespace.insert("wall".into(), 1.0.into());
+    let (_, diagnostics) = compile_input_with_diagnostics(input, &mut namespace);
+    assert_eq!(
+        diagnostics,
+        vec![Warning::ShadowedBuiltin("wall".into(), Span::from((31, 41, input))).into()]
+    )
+}
+
+#[test]
+fn test_no_diagnostics() {
+    let input = "
+        part Box:
+            part.add(Cube(4mm))
+        ";
+    assert_eq!(diagnose(input), vec![])
+}
+
+#[test]
+fn test_suggestions_for_typos() {
+    let input = "
+        part Box:
+            size = 4mn
+            part.add(Cuboidd(sise, 1mm, 1mm).move_too(1mm, 0mm, 0mm))
+        ";
+    let help: Vec<Option<String>> = diagnose(input)
+        .iter()
+        .map(|diagnostic| match diagnostic {
+            Diagnostic::Error(error) => error.help(),
+            Diagnostic::Warning(_) => None,
+        })
+        .collect();
+    assert_eq!(
+        help,
+        vec![
+            None, // size is never used because of the typo
+            Some("did you mean `mm`?".into()),
+            Some("did you mean `Cuboid`?".into()),
+        ]
+    );
+
+    let input = "
+        part Box:
+            size = 4mm
+            part.add(Cuboid(size, 1mm, 1mm).move_too(1mm, 0mm, 0mm))
+        ";
+    assert_eq!(
+        diagnose(input)[0].to_string(),
+        "error[E0014]: method move_too is not defined
+  |
+4 |             part.add(Cuboid(size, 1mm, 1mm).move_too(1mm, 0mm, 0mm))
+  |                      ^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^^
+help: did you mean `move_to`?
+"
+    )
+}
+
+fn diagnostics_severity_and_span(input: &str) -> Vec<(Severity, Option<Span>)> {
+    diagnose(input)
+        .iter()
+        .map(|diagnostic| (diagnostic.severity(), diagnostic.span().cloned()))
+        .collect()
+}
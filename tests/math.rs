@@ -1,5 +1,5 @@
 use anvil::{Cuboid, IntoLength};
-use oden::{Member, compile_input, eval_str};
+use oden::{Error, Member, Span, compile_input, eval_str};
 
 #[test]
 fn test_addition_before_multiplication() {
@@ -21,6 +21,70 @@ fn test_multiplication_with_brackets() {
     assert_eq!(eval_str("(1 + 2) * 3"), Ok(Member::Instance(Box::new(9.))));
 }
 
+#[test]
+fn test_separately_bracketed_operands() {
+    assert_eq!(
+        eval_str("(1 + 2) * (3 + 4)"),
+        Ok(Member::Instance(Box::new(21.)))
+    );
+}
+
+#[test]
+fn test_subtraction_is_left_associative() {
+    assert_eq!(eval_str("10 - 4 - 3"), Ok(Member::Instance(Box::new(3.))));
+}
+
+#[test]
+fn test_modulo_before_addition() {
+    assert_eq!(eval_str("1 + 7 % 4"), Ok(Member::Instance(Box::new(4.))));
+}
+
+#[test]
+fn test_power_before_multiplication() {
+    assert_eq!(eval_str("2 * 3 ^ 2"), Ok(Member::Instance(Box::new(18.))));
+    assert_eq!(eval_str("3 ** 2 * 2"), Ok(Member::Instance(Box::new(18.))));
+}
+
+#[test]
+fn test_power_is_right_associative() {
+    assert_eq!(eval_str("2 ^ 3 ^ 2"), Ok(Member::Instance(Box::new(512.))));
+}
+
+#[test]
+fn test_addition_before_intersection() {
+    assert_eq!(
+        eval_str("Cube(1m) + Cube(2m) & Cube(3m)"),
+        eval_str("(Cube(1m) + Cube(2m)) & Cube(3m)")
+    );
+    assert_ne!(
+        eval_str("Cube(1m) + Cube(2m) & Cube(3m)"),
+        eval_str("Cube(1m) + (Cube(2m) & Cube(3m))")
+    );
+}
+
+#[test]
+fn test_arithmetic_before_intersection() {
+    let input = "Cube(1m) & Sphere(1m) + 1m";
+    assert_eq!(
+        eval_str(input),
+        Err(Error::IncompatibleOperands {
+            operation: "add".into(),
+            left: "Part".into(),
+            right: "Length".into(),
+            span: Span::from((11, 26, input))
+        })
+    );
+}
+
+#[test]
+fn test_dangling_operator() {
+    let input = "Cube(1m) &";
+    assert_eq!(
+        eval_str(input),
+        Err(Error::ExpectedExpression(Span::from((0, 10, input))))
+    );
+}
+
 #[test]
 fn test_negative_number() {
     assert_eq!(eval_str("-2"), Ok(Member::Instance(Box::new(-2.))));
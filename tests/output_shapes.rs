@@ -1,4 +1,7 @@
-use anvil::{Axis, Cuboid, Cylinder, IntoLength, Plane, Rectangle, Sphere, point};
+use anvil::{
+    Axis, Circle, Cone, Cuboid, Cylinder, Dir, IntoLength, Path, Plane, Rectangle, Sphere, Torus,
+    point,
+};
 use oden::compile_input;
 
 #[test]
@@ -41,6 +44,63 @@ fn test_cylinder() {
     assert_eq!(actual, Ok(Cylinder::from_radius(5.mm(), 6.mm())))
 }
 
+#[test]
+fn test_cone() {
+    let text = "
+        part Box:
+            part.add(Cone(5mm, 2mm, 6mm))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(actual, Ok(Cone::from_radii(5.mm(), 2.mm(), 6.mm())))
+}
+
+#[test]
+fn test_torus() {
+    let text = "
+        part Box:
+            part.add(Torus(10mm, 2mm))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(actual, Ok(Torus::from_radii(10.mm(), 2.mm())))
+}
+
+#[test]
+fn test_wedge() {
+    let text = "
+        part Box:
+            part.add(Wedge(4mm, 5mm, 6mm))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(
+        actual,
+        Ok(Path::at(point!(0.mm(), 0.mm()))
+            .line_to(point!(4.mm(), 0.mm()))
+            .line_to(point!(0.mm(), 6.mm()))
+            .close()
+            .extrude(Plane::xz(), 5.mm())
+            .unwrap())
+    )
+}
+
+#[test]
+fn test_prism() {
+    let text = "
+        part Box:
+            part.add(Prism(3, 2mm, 5mm))
+        ";
+    let actual = compile_input(text);
+    let vertex = |angle: f64| point!(2.mm() * angle.cos(), 2.mm() * angle.sin());
+    assert_eq!(
+        actual,
+        Ok(Path::at(vertex(0.))
+            .line_to(vertex(std::f64::consts::TAU / 3.))
+            .line_to(vertex(2. * std::f64::consts::TAU / 3.))
+            .close()
+            .extrude(Plane::xy(), 5.mm())
+            .unwrap())
+    )
+}
+
 #[test]
 fn test_centered_cuboid_mixed_units() {
     let text = "
@@ -189,3 +249,194 @@ fn test_cuboid_circular_pattern() {
             .circular_pattern(Axis::<3>::z(), 4))
     )
 }
+
+#[test]
+fn test_rectangle_extrude_on_offset_plane() {
+    let text = "
+        part Box:
+            sketch = Rectangle(5mm, 6mm)
+            part.add(sketch.extrude(Plane.XY().offset(10mm), 7mm))
+        ";
+    assert_eq!(
+        compile_input(text),
+        Ok(Rectangle::from_dim(5.mm(), 6.mm())
+            .extrude(
+                Plane::new(
+                    point!(0.mm(), 0.mm(), 10.mm()),
+                    Dir::try_from([1., 0., 0.]).unwrap(),
+                    Dir::try_from([0., 0., 1.]).unwrap()
+                )
+                .unwrap(),
+                7.mm()
+            )
+            .unwrap())
+    )
+}
+
+#[test]
+fn test_cube_in_inches() {
+    let text = "
+        part Box:
+            part.add(Cube(1in))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(actual, Ok(Cuboid::from_m(0.0254, 0.0254, 0.0254)))
+}
+
+#[test]
+fn test_cube_in_scientific_notation() {
+    let text = "
+        part Box:
+            part.add(Cube(4e-3m))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(actual, Ok(Cuboid::from_m(4e-3, 4e-3, 4e-3)))
+}
+
+#[test]
+fn test_cube_with_default_units() {
+    let text = "
+        units mm
+        part Box:
+            part.add(Cube(4))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(actual, Ok(Cuboid::from_mm(4., 4., 4.)))
+}
+
+#[test]
+fn test_rotation_with_default_angle_unit() {
+    let text = "
+        units deg
+        part Box:
+            part.add(Rectangle(4mm, 4mm).extrude(Plane.XY().rotate(Axis.X(), 90), 1mm))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(
+        actual,
+        compile_input(
+            "
+        part Box:
+            part.add(Rectangle(4mm, 4mm).extrude(Plane.XY().rotate(Axis.X(), 90deg), 1mm))
+        "
+        )
+    )
+}
+
+#[test]
+fn test_union_operator() {
+    let text = "
+        part Box:
+            part.add(Cuboid(1m, 1m, 5m) + Sphere(1m))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(
+        actual,
+        Ok(Cuboid::from_m(1., 1., 5.).add(&Sphere::from_radius(1.m())))
+    )
+}
+
+#[test]
+fn test_difference_operator() {
+    let text = "
+        part Box:
+            part.add(Cuboid(1m, 1m, 5m) - Sphere(1m))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(
+        actual,
+        Ok(Cuboid::from_m(1., 1., 5.).subtract(&Sphere::from_radius(1.m())))
+    )
+}
+
+#[test]
+fn test_intersection_operator() {
+    let text = "
+        part Box:
+            part.add(Cuboid(1m, 1m, 5m) & Sphere(1m))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(
+        actual,
+        Ok(Cuboid::from_m(1., 1., 5.).intersect(&Sphere::from_radius(1.m())))
+    )
+}
+
+#[test]
+fn test_boolean_operator_precedence() {
+    let text = "
+        part Box:
+            part.add(Cuboid(1m, 1m, 5m) + Cuboid(5m, 1m, 1m) & Sphere(2m) - Sphere(1m))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(
+        actual,
+        Ok(Cuboid::from_m(1., 1., 5.)
+            .add(&Cuboid::from_m(5., 1., 1.))
+            .intersect(&Sphere::from_radius(2.m()).subtract(&Sphere::from_radius(1.m()))))
+    )
+}
+
+#[test]
+fn test_sketch_operators() {
+    let text = "
+        part Box:
+            sketch = Rectangle(2m, 2m) - Circle(0.5m) + Rectangle(4m, 1m) & Circle(1.5m)
+            part.add(sketch.extrude(Plane.XY(), 1m))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(
+        actual,
+        Ok(Rectangle::from_dim(2.m(), 2.m())
+            .subtract(&Circle::from_radius(0.5.m()))
+            .add(&Rectangle::from_dim(4.m(), 1.m()))
+            .intersect(&Circle::from_radius(1.5.m()))
+            .extrude(Plane::xy(), 1.m())
+            .unwrap())
+    )
+}
+
+#[test]
+fn test_add_list_of_parts() {
+    let text = "
+        part Box:
+            sizes = [
+                1m,
+                2m,
+            ]
+            part.add([Cube(size) for size in sizes])
+        ";
+    let actual = compile_input(text);
+    assert_eq!(
+        actual,
+        Ok(Cuboid::from_m(1., 1., 1.).add(&Cuboid::from_m(2., 2., 2.)))
+    )
+}
+
+#[test]
+fn test_record_of_dimensions() {
+    let text = "
+        part Box:
+            plate = {
+                width: 4mm,
+                depth: 5mm,
+                height: 6mm,
+            }
+            part.add(Cuboid(plate.width, plate.depth, plate.height))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(actual, Ok(Cuboid::from_mm(4., 5., 6.)))
+}
+
+#[test]
+fn test_unicode_identifiers_and_comments() {
+    let text = "
+        part Würfel: // ein Würfel für die Größe
+            /* Maße
+               in mm */
+            höhe = 4mm
+            part.add(Cuboid(höhe, 5mm, 6mm))
+        ";
+    let actual = compile_input(text);
+    assert_eq!(actual, Ok(Cuboid::from_mm(4., 5., 6.)))
+}